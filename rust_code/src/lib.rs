@@ -7,9 +7,29 @@
 
 // --- Imports ---
 
+use config::{Config, File, FileFormat};  // Used for loading config files
 use meval::{Context, Expr};         // Used for parsing and evaluating expressions
-use serde::Deserialize;             // Used for config deserialization from .ini
+use rand::rngs::StdRng;             // Seedable RNG for stochastic solvers
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize}; // Config deserialization and JSON output
+use std::cell::Cell;                // Interior-mutable evaluation counter
+use std::collections::HashMap;      // Named expression parameters
 use std::error::Error;              // Generic error handling trait
+use std::fmt;                       // Display impls for error types
+use std::fs::File as OutputFile;    // Output file handles
+use std::io::{self, BufWriter, Write}; // Buffered output writers and stdout
+use std::path::Path;                // Config file locations
+use std::rc::Rc;                    // Shared ownership of ODE functions across solvers
+use std::time::{Duration, Instant}; // Solve timing statistics
+
+/// Boxed right-hand side `f(t, y)` of the ODE dy/dt = f(t, y)
+pub type OdeFunction = Box<dyn Fn(f64, f64) -> f64 + 'static>;
+
+/// Boxed right-hand side `f(t, y)` of an N-dimensional system dy/dt = f(t, y)
+pub type SystemFunction = Box<dyn Fn(f64, &[f64]) -> Vec<f64> + 'static>;
+
+/// List of (x, y) points in a 2D phase plane, e.g. a sampled nullcline
+pub type PhasePoints = Vec<(f64, f64)>;
 
 // ================================
 // Section: Configuration Structs
@@ -35,10 +55,61 @@ pub struct OdeConfig {
     pub expression: String,     // String expression, e.g., "cos(t) - y"
 }
 
+/// Supported output formats for exported solutions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputFormat {
+    #[default]
+    Csv,                        // `t,y(t)` rows
+    Json,                       // Metadata plus an array of {"t", "y"} objects
+    Tsv,                        // Tab-separated `t`, `y(t)` rows
+    Ndjson,                     // One {"t", "y"} JSON object per line
+}
+
 /// Configuration for output behavior (e.g., CSV file path)
 #[derive(Debug, Deserialize)]
 pub struct OutputConfig {
-    pub csv_file: String,       // File name to export results to
+    pub csv_file: String,       // File name to export results to (any format), `-` for stdout
+    #[serde(default)]
+    pub format: OutputFormat,   // Output format, CSV when omitted
+    #[serde(default)]
+    pub precision: Option<usize>, // Scientific-notation digits, shortest round-trip when omitted
+    #[serde(default)]
+    pub delimiter: Option<String>, // Single-character CSV delimiter, `,` when omitted
+    #[serde(default)]
+    pub include_cumulative: bool, // Add running max/min to every point of text output
+}
+
+/// Optional time-stepping settings (`[solver]` section)
+#[derive(Debug, Deserialize)]
+pub struct MethodConfig {
+    #[serde(default)]
+    pub method: SolverMethod,   // "forward_euler" (default) or "backward_euler"
+    pub tolerance: Option<f64>, // Newton tolerance for implicit steps
+    pub max_iterations: Option<usize>, // Newton iteration limit for implicit steps
+    pub divergence_bound: Option<f64>, // Flag |y| above this as divergence
+    pub mesh_tolerance: Option<f64>, // Merge near-duplicate mesh points (relative)
+    #[serde(default)]
+    pub stability_check: bool,  // Warn about unstable forward Euler steps (opt-in)
+}
+
+/// Optional event that stops integration when its expression changes sign
+#[derive(Debug, Deserialize)]
+pub struct EventConfig {
+    pub expression: String,     // Event function g(t, y), e.g., "y"
+}
+
+impl OutputConfig {
+    /// Returns the configured CSV delimiter as a byte, `,` by default
+    fn csv_delimiter(&self) -> Result<u8, Box<dyn Error>> {
+        match self.delimiter.as_deref() {
+            None => Ok(b','),
+            Some(d) if d.len() == 1 && d.is_ascii() => Ok(d.as_bytes()[0]),
+            Some(d) => {
+                Err(format!("CSV delimiter must be a single ASCII character, got `{}`", d).into())
+            }
+        }
+    }
 }
 
 /// Aggregated solver configuration loaded from `config.ini`
@@ -48,6 +119,327 @@ pub struct SolverConfig {
     pub initial_conditions: InitialConditions,   // Initial condition
     pub ode_function: OdeConfig,                 // ODE function config (matches [ode_function])
     pub output: OutputConfig,                    // Output config
+    pub event: Option<EventConfig>,              // Optional stopping event
+    pub solver: Option<MethodConfig>,            // Optional time-stepping settings
+    #[serde(default)]
+    pub parameters: HashMap<String, f64>,        // Named constants for the expressions
+}
+
+impl SolverConfig {
+    /// Loads the solver configuration from a file, picking the format from
+    /// the extension: `.ini`, `.toml` or `.json`.
+    ///
+    /// All formats use the same section names (`mesh_1_d`, `initial_conditions`,
+    /// `ode_function`, `output`).
+    ///
+    /// # Arguments
+    /// * `path` - Path to the configuration file
+    ///
+    /// # Returns
+    /// * `Result<SolverConfig, Box<dyn Error>>` - Parsed config or descriptive error
+    pub fn from_path(path: &Path) -> Result<SolverConfig, Box<dyn Error>> {
+        let extension = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(|ext| ext.to_ascii_lowercase());
+        let format = match extension.as_deref() {
+            Some("ini") => FileFormat::Ini,
+            Some("toml") => FileFormat::Toml,
+            Some("json") => FileFormat::Json,
+            _ => {
+                return Err(format!(
+                    "Unsupported config format for `{}` (expected .ini, .toml or .json)",
+                    path.display()
+                )
+                .into());
+            }
+        };
+
+        let settings = Config::builder()
+            .add_source(File::from(path).format(format))
+            .build()?;
+        Ok(settings.try_deserialize()?)
+    }
+
+    /// Parses the expressions and builds a solved `EulerSolver1D` from this config
+    ///
+    /// # Returns
+    /// * `Result<EulerSolver1D, Box<dyn Error>>` - Finished solver or descriptive error
+    pub fn build_solver(&self) -> Result<EulerSolver1D, Box<dyn Error>> {
+        // Parse the user-defined ODE expression into a callable function
+        let expression_fn =
+            parse_expression(self.ode_function.expression.clone(), &self.parameters)?;
+
+        // Parse the optional stopping event
+        let mut options = SolverOptions::default();
+        if let Some(event) = &self.event {
+            options.event = Some(parse_expression(event.expression.clone(), &self.parameters)?);
+        }
+
+        // Apply the optional time-stepping settings
+        if let Some(settings) = &self.solver {
+            options.method = settings.method;
+            options.tolerance = settings.tolerance.unwrap_or(options.tolerance);
+            options.max_iterations = settings.max_iterations.unwrap_or(options.max_iterations);
+            options.divergence_bound = settings.divergence_bound;
+            options.mesh_tolerance = settings.mesh_tolerance.unwrap_or(options.mesh_tolerance);
+            options.stability_check = settings.stability_check;
+        }
+
+        // Create and run the Euler solver
+        let mesh = EulerSolver1D::generate_mesh(
+            self.mesh_1_d.domain_start,
+            self.mesh_1_d.domain_end,
+            self.mesh_1_d.n,
+        );
+        let mut solver =
+            EulerSolver1D::with_options(expression_fn, mesh, self.initial_conditions.y_0, options)?;
+        solver.expression = Some(self.ode_function.expression.clone());
+        Ok(solver)
+    }
+}
+
+/// Runs the whole pipeline from a config file: loads and deserializes it,
+/// builds and solves the ODE, and exports the result as configured.
+///
+/// # Arguments
+/// * `path` - Path to an `.ini`, `.toml` or `.json` config file
+///
+/// # Returns
+/// * `Result<EulerSolver1D, Box<dyn Error>>` - Finished solver or descriptive error
+pub fn run_from_config(path: &Path) -> Result<EulerSolver1D, Box<dyn Error>> {
+    run_from_config_in(path, None)
+}
+
+/// Same as `run_from_config`, but relative output paths are resolved against
+/// `workdir` instead of the current directory (see `resolve_output_path`).
+///
+/// # Arguments
+/// * `path` - Path to an `.ini`, `.toml` or `.json` config file
+/// * `workdir` - Optional base directory for outputs; must exist
+///
+/// # Returns
+/// * `Result<EulerSolver1D, Box<dyn Error>>` - Finished solver or descriptive error
+pub fn run_from_config_in(
+    path: &Path,
+    workdir: Option<&Path>,
+) -> Result<EulerSolver1D, Box<dyn Error>> {
+    run_config(&SolverConfig::from_path(path)?, workdir)
+}
+
+/// Builds, solves and exports an already loaded config
+///
+/// Lets callers inspect the config as well, e.g. to check whether the
+/// solution is being streamed to stdout (`csv_file = "-"`).
+///
+/// # Arguments
+/// * `config` - Deserialized solver config
+/// * `workdir` - Optional base directory for outputs; must exist
+///
+/// # Returns
+/// * `Result<EulerSolver1D, Box<dyn Error>>` - Finished solver or descriptive error
+pub fn run_config(
+    config: &SolverConfig,
+    workdir: Option<&Path>,
+) -> Result<EulerSolver1D, Box<dyn Error>> {
+    if let Some(dir) = workdir.filter(|dir| !dir.is_dir()) {
+        return Err(format!("Working directory `{}` does not exist", dir.display()).into());
+    }
+    let solver = config.build_solver()?;
+    solver.export_in(&config.output, workdir)?;
+    Ok(solver)
+}
+
+/// Resolves an output file name against an optional working directory
+///
+/// Precedence: `-` (stdout) and absolute paths are used as given; relative
+/// paths are joined onto `workdir` when one is set and otherwise stay
+/// relative to the current directory. The config file's own directory is
+/// never used, so moving a config does not move its outputs.
+///
+/// # Arguments
+/// * `filename` - Output file name from the config
+/// * `workdir` - Optional base directory for relative paths
+///
+/// # Returns
+/// * `String` - Path to open for writing
+pub fn resolve_output_path(filename: &str, workdir: Option<&Path>) -> String {
+    match workdir {
+        Some(dir) if filename != "-" && Path::new(filename).is_relative() => {
+            dir.join(filename).to_string_lossy().into_owned()
+        }
+        _ => filename.to_string(),
+    }
+}
+
+// ================================
+// Section: Error Types
+// ================================
+
+/// Errors reported while setting up or running the solver
+#[derive(Debug, Clone, PartialEq)]
+pub enum SolverError {
+    InvalidMesh(String),        // Mesh too short, non-finite or not increasing
+    NonConvergence { step: usize, t: f64 }, // Implicit step did not converge
+    Diverged { step: usize, t: f64, value: f64 }, // Bad `value` at mesh index `step`
+    MissingField(&'static str), // Required builder field was not set
+    InvalidExpression(String),  // Expression could not be parsed
+    InvalidData(String),        // Input data could not be read or parsed
+    Output(String),             // Diagnostic output could not be written
+}
+
+impl fmt::Display for SolverError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SolverError::InvalidMesh(msg) => write!(f, "Invalid mesh: {}", msg),
+            SolverError::NonConvergence { step, t } => write!(
+                f,
+                "Implicit step {} did not converge at t = {}",
+                step, t
+            ),
+            SolverError::Diverged { step, t, value } => write!(
+                f,
+                "Solution diverged at step {} (t = {}, y = {})",
+                step, t, value
+            ),
+            SolverError::MissingField(field) => write!(f, "Missing required field `{}`", field),
+            SolverError::InvalidExpression(msg) => write!(f, "Invalid expression: {}", msg),
+            SolverError::InvalidData(msg) => write!(f, "Invalid data: {}", msg),
+            SolverError::Output(msg) => write!(f, "Failed to write output: {}", msg),
+        }
+    }
+}
+
+impl Error for SolverError {}
+
+// ================================
+// Section: Output Records & Helpers
+// ================================
+
+/// Single (t, y) sample in JSON output
+#[derive(Debug, Serialize)]
+struct JsonPoint {
+    t: f64,
+    y: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cummax_y: Option<f64>,      // Running maximum, with `include_cumulative`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cummin_y: Option<f64>,      // Running minimum, with `include_cumulative`
+}
+
+/// Opens an output destination, where `-` means standard output
+fn open_output(filename: &str) -> Result<Box<dyn Write>, Box<dyn Error>> {
+    if filename == "-" {
+        Ok(Box::new(io::stdout().lock()))
+    } else {
+        Ok(Box::new(BufWriter::new(OutputFile::create(filename)?)))
+    }
+}
+
+/// Formats a value for delimited output
+///
+/// `None` gives the shortest string that parses back to the same `f64`;
+/// `Some(digits)` gives scientific notation with that many digits after the
+/// point (16 or more is always round-trippable).
+fn format_value(value: f64, precision: Option<usize>) -> String {
+    match precision {
+        Some(digits) => format!("{:.*e}", digits, value),
+        None => value.to_string(),
+    }
+}
+
+/// Confirms an export on the console, unless the data itself went to stdout
+fn report_export(filename: &str) {
+    if filename != "-" {
+        println!("Solution exported to `{}`", filename);
+    }
+}
+
+/// Layout of the JSON output document
+#[derive(Debug, Serialize)]
+struct JsonSolution<'a> {
+    t_start: f64,
+    t_end: f64,
+    num_steps: usize,
+    expression: Option<&'a str>,
+    warnings: &'a [Warning],
+    solution: Vec<JsonPoint>,
+}
+
+// ================================
+// Section: Binary Output
+// ================================
+
+/// Magic bytes at the start of every binary solution file (format version 1)
+const BINARY_MAGIC: &[u8; 8] = b"EULRBIN1";
+
+/// Size of the binary header in bytes; the data starts 8-byte aligned after it
+const BINARY_HEADER_LEN: usize = 32;
+
+/// Solution read back from a file written by `EulerSolver1D::export_binary`
+///
+/// File layout, all values little-endian, with the columns stored one after
+/// the other (column-major):
+///
+/// | Offset         | Size      | Field                                |
+/// |----------------|-----------|--------------------------------------|
+/// | 0              | 8         | magic `EULRBIN1`                     |
+/// | 8              | 8         | `len`, number of mesh points (`u64`) |
+/// | 16             | 8         | `t_start` (`f64`)                    |
+/// | 24             | 8         | `t_end` (`f64`)                      |
+/// | 32             | 8 * `len` | mesh times (`f64`)                   |
+/// | 32 + 8 * `len` | 8 * `len` | solution values (`f64`)              |
+///
+/// Storing the mesh keeps non-uniform meshes and meshes cut short by an
+/// event exact.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BinarySolution {
+    pub t_start: f64,           // Domain start
+    pub t_end: f64,             // Domain end
+    pub mesh: Vec<f64>,         // Time points
+    pub solution: Vec<f64>,     // Solution values at the mesh points
+}
+
+impl BinarySolution {
+    /// Reads a binary solution file, checking the magic bytes and length
+    ///
+    /// # Arguments
+    /// * `path` - Path to a file written by `export_binary`
+    ///
+    /// # Returns
+    /// * `Result<BinarySolution, Box<dyn Error>>` - Decoded solution or descriptive error
+    pub fn from_binary(path: &str) -> Result<BinarySolution, Box<dyn Error>> {
+        let bytes = std::fs::read(path)?;
+        if bytes.len() < BINARY_HEADER_LEN || &bytes[0..8] != BINARY_MAGIC {
+            return Err(format!("`{}` is not a binary solution file", path).into());
+        }
+
+        let word = |offset: usize| -> [u8; 8] { bytes[offset..offset + 8].try_into().unwrap() };
+        let len = u64::from_le_bytes(word(8)) as usize;
+        let expected = len
+            .checked_mul(16)
+            .and_then(|n| n.checked_add(BINARY_HEADER_LEN));
+        if expected != Some(bytes.len()) {
+            return Err(format!(
+                "`{}` has {} bytes, which does not match its header length of {} values",
+                path,
+                bytes.len(),
+                len
+            )
+            .into());
+        }
+
+        let column = |c: usize| -> Vec<f64> {
+            let start = BINARY_HEADER_LEN + 8 * len * c;
+            (0..len).map(|i| f64::from_le_bytes(word(start + 8 * i))).collect()
+        };
+        Ok(BinarySolution {
+            t_start: f64::from_le_bytes(word(16)),
+            t_end: f64::from_le_bytes(word(24)),
+            mesh: column(0),
+            solution: column(1),
+        })
+    }
 }
 
 // ================================
@@ -56,18 +448,174 @@ pub struct SolverConfig {
 
 /// Euler 1D solver state and methods
 pub struct EulerSolver1D {
-    pub expression_fn: Box<dyn Fn(f64, f64) -> f64>, // Evaluated ODE function
-    pub t_start: f64,          // Domain start
-    pub t_end: f64,            // Domain end
+    pub expression_fn: OdeFunction, // Evaluated ODE function
+    pub t_start: f64,          // Domain start (first mesh point)
+    pub t_end: f64,            // Domain end (last mesh point)
     pub y0: f64,               // Initial condition
     pub num_steps: usize,      // Number of steps
     pub mesh: Vec<f64>,        // Discretized mesh of time points
-    pub step_size: f64,        // Time step size
+    pub step_size: f64,        // Mean time step size (exact for uniform meshes)
     pub solution: Vec<f64>,    // Computed solution values at mesh points
+    pub expression: Option<String>, // Source expression, if known (export metadata)
+    pub options: SolverOptions, // Optional solver settings (events, ...)
+    pub event_time: Option<f64>, // Time at which the event stopped integration, if any
+    pub stats: SolverStats,    // Timing and step statistics of the solve
+    pub warnings: Vec<Warning>, // Diagnostics collected while solving
+    evaluations: Cell<usize>,  // Running count of `expression_fn` calls
+}
+
+/// Time-stepping scheme used by the solver
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SolverMethod {
+    #[default]
+    ForwardEuler,               // Explicit y[k+1] = y[k] + h*f(t[k], y[k])
+    BackwardEuler,              // Implicit y[k+1] = y[k] + h*f(t[k+1], y[k+1]), for stiff ODEs
+}
+
+/// Optional settings for `EulerSolver1D::with_options`
+///
+/// `SolverOptions::default()` gives plain forward Euler over the whole mesh.
+pub struct SolverOptions {
+    /// Event function g(t, y). When it changes sign between two consecutive
+    /// steps, integration stops at the linearly interpolated crossing time.
+    pub event: Option<OdeFunction>,
+    /// Time-stepping scheme
+    pub method: SolverMethod,
+    /// Newton convergence tolerance for implicit steps, relative to max(1, |y|)
+    pub tolerance: f64,
+    /// Maximum Newton iterations per implicit step
+    pub max_iterations: usize,
+    /// Relative tolerance for near-duplicate mesh points. Consecutive points
+    /// closer than `mesh_tolerance * max(|a|, |b|)` are merged (the first is
+    /// kept) before the strict monotonicity check; `0.0` disables merging.
+    pub mesh_tolerance: f64,
+    /// Optional bound on |y|; exceeding it is reported as divergence even
+    /// before the solution overflows. Non-finite values always are.
+    pub divergence_bound: Option<f64>,
+    /// Optional observer called every `stride` steps while solving; the
+    /// intended hook for CLI progress bars and intermediate logging
+    pub progress: Option<ProgressCallback>,
+    /// Opt-in check for forward Euler steps beyond the stability limit,
+    /// reported as an `unstable_step` warning. Costs two extra evaluations
+    /// per step, which are included in `stats`.
+    pub stability_check: bool,
+}
+
+/// Observer for long-running solves, called as `callback(step, t, y)` after
+/// every `stride` steps
+///
+/// The callback only receives copies of the current state, so it cannot
+/// change the solution. The solve loop runs in chunks of `stride` steps with
+/// the callback between chunks, so there is no per-step cost, and without a
+/// callback the loop is unchanged.
+///
+/// A non-zero `min_output_interval` additionally throttles the callback to
+/// at most one call per wall-clock interval; stride points in between are
+/// skipped. The first stride point is always emitted.
+pub struct ProgressCallback {
+    pub stride: usize,          // Steps between calls (0 is treated as 1)
+    pub callback: Box<dyn FnMut(usize, f64, f64)>, // Receives (step, t, y)
+    pub min_output_interval: Duration, // Minimum wall-clock time between calls
+    last_emit: Option<Instant>, // Time of the previous call, if any
+}
+
+impl ProgressCallback {
+    /// Creates an observer invoked every `stride` steps
+    pub fn new(stride: usize, callback: impl FnMut(usize, f64, f64) + 'static) -> Self {
+        Self {
+            stride,
+            callback: Box::new(callback),
+            min_output_interval: Duration::ZERO,
+            last_emit: None,
+        }
+    }
+
+    /// Limits the callback to at most one call per `interval`
+    pub fn with_min_output_interval(mut self, interval: Duration) -> Self {
+        self.min_output_interval = interval;
+        self
+    }
+
+    /// Decides whether a stride point is emitted under the throttle
+    ///
+    /// `now` is only queried when throttling is enabled, so an unthrottled
+    /// observer never reads the clock.
+    fn should_emit(&mut self, now: impl FnOnce() -> Instant) -> bool {
+        if self.min_output_interval.is_zero() {
+            return true;
+        }
+        let now = now();
+        match self.last_emit {
+            Some(last) if now.duration_since(last) < self.min_output_interval => false,
+            _ => {
+                self.last_emit = Some(now);
+                true
+            }
+        }
+    }
+}
+
+impl Default for SolverOptions {
+    fn default() -> Self {
+        Self {
+            event: None,
+            method: SolverMethod::ForwardEuler,
+            tolerance: 1e-10,
+            max_iterations: 50,
+            mesh_tolerance: 0.0,
+            divergence_bound: None,
+            progress: None,
+            stability_check: false,
+        }
+    }
+}
+
+/// Performance statistics recorded while computing `solution`
+///
+/// `evaluations` counts every call of the ODE function, so implicit methods
+/// report their Newton and finite-difference evaluations as well. The step
+/// extremes refer to the mesh actually integrated, after any event cut.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct SolverStats {
+    pub evaluations: usize,     // Number of `f(t, y)` evaluations
+    pub duration: Duration,     // Wall-clock time of the integration loop
+    pub min_step: f64,          // Smallest step size used
+    pub max_step: f64,          // Largest step size used
+}
+
+impl fmt::Display for SolverStats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:.1?}, {} evals", self.duration, self.evaluations)
+    }
+}
+
+/// Structured solver diagnostic, exported with the JSON metadata
+///
+/// Warnings never change the solution; they flag results that automated
+/// pipelines may want to reject or re-run.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Warning {
+    pub code: &'static str,     // Stable machine-readable identifier
+    pub message: String,        // Human-readable description
+    pub t: f64,                 // Time at which the condition was first detected
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{}] at t = {}: {}", self.code, self.t, self.message)
+    }
+}
+
+/// Sign change of the event function found during a solve
+struct EventCrossing {
+    step: usize,                // Last mesh index before the crossing
+    t: f64,                     // Interpolated crossing time
+    y: f64,                     // Interpolated solution value at `t`
 }
 
 impl EulerSolver1D {
-    /// Constructs a new Euler solver instance and computes the solution.
+    /// Constructs a new Euler solver instance on a uniform mesh and computes the solution.
     ///
     /// # Arguments
     /// * `expression_fn` - Parsed ODE function (f64, f64) -> f64
@@ -76,94 +624,1868 @@ impl EulerSolver1D {
     /// * `num_steps` - Number of steps (mesh resolution)
     ///
     /// # Returns
-    /// * `Self` - Solver object with computed mesh and solution
+    /// * `Result<Self, SolverError>` - Solver object with computed mesh and solution,
+    ///   `SolverError::InvalidMesh` if `num_steps` is zero or `t_end <= t_start`,
+    ///   or `SolverError::Diverged` with the step, time and value if the solution
+    ///   becomes non-finite
     pub fn new(
         expression_fn: impl Fn(f64, f64) -> f64 + 'static,
         t_start: f64,
         t_end: f64,
         y0: f64,
         num_steps: usize,
-    ) -> Self {
+    ) -> Result<Self, SolverError> {
         let mesh = Self::generate_mesh(t_start, t_end, num_steps);
-        let step_size = (t_end - t_start) / num_steps as f64;
+        Self::with_options(expression_fn, mesh, y0, SolverOptions::default())
+    }
+
+    /// Constructs a solver on a user-supplied (possibly non-uniform) mesh
+    /// and computes the solution.
+    ///
+    /// # Arguments
+    /// * `expression_fn` - Parsed ODE function (f64, f64) -> f64
+    /// * `mesh` - Strictly increasing time points, at least two
+    /// * `y0` - Initial y value at `mesh[0]`
+    ///
+    /// # Returns
+    /// * `Result<Self, SolverError>` - Solver object or `SolverError::InvalidMesh`
+    pub fn with_mesh(
+        expression_fn: impl Fn(f64, f64) -> f64 + 'static,
+        mesh: Vec<f64>,
+        y0: f64,
+    ) -> Result<Self, SolverError> {
+        Self::with_options(expression_fn, mesh, y0, SolverOptions::default())
+    }
+
+    /// Constructs a solver on a user-supplied mesh with optional settings
+    /// (see `SolverOptions`) and computes the solution.
+    ///
+    /// If an event is configured and triggers, `mesh` and `solution` end at
+    /// the interpolated crossing and `event_time` records it; otherwise the
+    /// solve runs over the whole mesh.
+    ///
+    /// # Arguments
+    /// * `expression_fn` - Parsed ODE function (f64, f64) -> f64
+    /// * `mesh` - Strictly increasing time points (up to `options.mesh_tolerance`), at least two
+    /// * `y0` - Initial y value at `mesh[0]`
+    /// * `options` - Optional solver settings
+    ///
+    /// # Returns
+    /// * `Result<Self, SolverError>` - Solver object, `SolverError::InvalidMesh`,
+    ///   `SolverError::NonConvergence` if an implicit step fails, or
+    ///   `SolverError::Diverged` if the solution becomes non-finite or exceeds
+    ///   `options.divergence_bound`
+    pub fn with_options(
+        expression_fn: impl Fn(f64, f64) -> f64 + 'static,
+        mesh: Vec<f64>,
+        y0: f64,
+        options: SolverOptions,
+    ) -> Result<Self, SolverError> {
+        let mesh = Self::merge_near_duplicates(mesh, options.mesh_tolerance);
+        Self::validate_mesh(&mesh)?;
+        Self::from_mesh(Box::new(expression_fn), mesh, y0, options)
+    }
+
+    /// Builds the solver state around an already validated mesh and runs the solve
+    fn from_mesh(
+        expression_fn: OdeFunction,
+        mesh: Vec<f64>,
+        y0: f64,
+        options: SolverOptions,
+    ) -> Result<Self, SolverError> {
+        let num_steps = mesh.len() - 1;
+        let t_start = mesh[0];
+        let t_end = mesh[num_steps];
         let mut solver = Self {
-            expression_fn: Box::new(expression_fn),
+            expression_fn,
             t_start,
             t_end,
             y0,
             num_steps,
             mesh,
-            step_size,
+            step_size: (t_end - t_start) / num_steps as f64,
             solution: Vec::new(),
+            expression: None,
+            options,
+            event_time: None,
+            stats: SolverStats::default(),
+            warnings: Vec::new(),
+            evaluations: Cell::new(0),
         };
-        solver.solution = solver.solve();  // Run computation
-        solver
+        let mut progress = solver.options.progress.take();  // Needs `&mut` while solving
+        let started = Instant::now();
+        let result = solver.solve(progress.as_mut());  // Run computation
+        solver.options.progress = progress;
+        let (solution, crossing) = result?;
+        solver.solution = solution;
+        if let Some(crossing) = crossing {
+            solver.truncate_at_event(crossing);
+        }
+        if solver.options.stability_check {
+            solver.check_stability();
+        }
+        solver.record_stats(started.elapsed());
+        Ok(solver)
+    }
+
+    /// Fills `stats` from the evaluation counter and the integrated mesh
+    fn record_stats(&mut self, duration: Duration) {
+        let steps = self.mesh.windows(2).map(|w| w[1] - w[0]);
+        self.stats = SolverStats {
+            evaluations: self.evaluations.get(),
+            duration,
+            min_step: steps.clone().fold(f64::INFINITY, f64::min),
+            max_step: steps.fold(0.0, f64::max),
+        };
+    }
+
+    /// Records an `unstable_step` warning at the first forward Euler step with
+    /// `h * |df/dy| > 2`, where the explicit update amplifies errors
+    ///
+    /// Only runs when `options.stability_check` is set. `df/dy` is a central
+    /// finite difference at each mesh point; the evaluations and the time
+    /// spent are included in `stats`.
+    fn check_stability(&mut self) {
+        if self.options.method != SolverMethod::ForwardEuler {
+            return;                 // Backward Euler is unconditionally stable
+        }
+        let f = |t, y| self.eval(t, y);
+        for k in 0..self.num_steps {
+            let (t, y, h) = (self.mesh[k], self.solution[k], self.mesh[k + 1] - self.mesh[k]);
+            let delta = f64::EPSILON.cbrt() * y.abs().max(1.0);
+            let dfdy = (f(t, y + delta) - f(t, y - delta)) / (2.0 * delta);
+            if h * dfdy.abs() > 2.0 {
+                self.warnings.push(Warning {
+                    code: "unstable_step",
+                    message: format!(
+                        "forward Euler step h = {} exceeds the stability limit 2/|df/dy| = {}",
+                        h,
+                        2.0 / dfdy.abs()
+                    ),
+                    t,
+                });
+                return;
+            }
+        }
+    }
+
+    /// Evaluates the ODE function, counting the call for `stats`
+    fn eval(&self, t: f64, y: f64) -> f64 {
+        self.evaluations.set(self.evaluations.get() + 1);
+        (self.expression_fn)(t, y)
+    }
+
+    /// Cuts the mesh and solution at an event crossing and updates the
+    /// derived fields to describe the shortened domain
+    fn truncate_at_event(&mut self, crossing: EventCrossing) {
+        self.mesh.truncate(crossing.step + 1);
+        self.mesh.push(crossing.t);
+        self.solution.truncate(crossing.step + 1);
+        self.solution.push(crossing.y);
+        self.num_steps = self.mesh.len() - 1;
+        self.t_end = crossing.t;
+        self.step_size = (self.t_end - self.t_start) / self.num_steps as f64;
+        self.event_time = Some(crossing.t);
     }
 
     /// Generates a 1D uniform mesh from `t_start` to `t_end` with `n` steps
-    fn generate_mesh(t_start: f64, t_end: f64, n: usize) -> Vec<f64> {
+    pub fn generate_mesh(t_start: f64, t_end: f64, n: usize) -> Vec<f64> {
         let h = (t_end - t_start) / n as f64;
         (0..=n).map(|i| t_start + i as f64 * h).collect()
     }
 
-    /// Solves the ODE using the forward Euler method
+    /// Drops mesh points that lie within `rel_tol * max(|a|, |b|)` of the
+    /// previously kept point, absorbing float rounding in loaded meshes
+    fn merge_near_duplicates(mesh: Vec<f64>, rel_tol: f64) -> Vec<f64> {
+        if rel_tol <= 0.0 {
+            return mesh;
+        }
+        let mut merged: Vec<f64> = Vec::with_capacity(mesh.len());
+        for t in mesh {
+            match merged.last() {
+                Some(&prev) if (t - prev).abs() <= rel_tol * prev.abs().max(t.abs()) => {}
+                _ => merged.push(t),
+            }
+        }
+        merged
+    }
+
+    /// Checks that a mesh has at least two finite, strictly increasing points
+    fn validate_mesh(mesh: &[f64]) -> Result<(), SolverError> {
+        if mesh.len() < 2 {
+            return Err(SolverError::InvalidMesh(format!(
+                "mesh needs at least two points, got {}",
+                mesh.len()
+            )));
+        }
+        if let Some(t) = mesh.iter().find(|t| !t.is_finite()) {
+            return Err(SolverError::InvalidMesh(format!("mesh point {} is not finite", t)));
+        }
+        if let Some(k) = mesh.windows(2).position(|w| w[1] <= w[0]) {
+            return Err(SolverError::InvalidMesh(format!(
+                "mesh is not strictly increasing at index {} ({} -> {})",
+                k + 1,
+                mesh[k],
+                mesh[k + 1]
+            )));
+        }
+        Ok(())
+    }
+
+    /// Solves the ODE from `y0` with the configured method and event
     ///
-    /// Returns a vector `y` containing approximated solution values
-    fn solve(&self) -> Vec<f64> {
+    /// Returns a vector `y` containing approximated solution values, and the
+    /// event crossing if the configured event triggered
+    fn solve(
+        &self,
+        progress: Option<&mut ProgressCallback>,
+    ) -> Result<(Vec<f64>, Option<EventCrossing>), SolverError> {
+        self.integrate(self.y0, self.options.event.as_deref(), progress)
+    }
+
+    /// Integrates over the mesh from an initial value using the configured Euler method
+    ///
+    /// Each step uses the local spacing `mesh[k + 1] - mesh[k]`, so
+    /// non-uniform meshes are handled directly. Integration stops early if
+    /// `event` changes sign. The steps run in chunks of `progress.stride`
+    /// with the observer called in between; without an observer the whole
+    /// mesh is a single chunk.
+    fn integrate(
+        &self,
+        y0: f64,
+        event: Option<&dyn Fn(f64, f64) -> f64>,
+        mut progress: Option<&mut ProgressCallback>,
+    ) -> Result<(Vec<f64>, Option<EventCrossing>), SolverError> {
         let mut y = vec![0.0; self.num_steps + 1];
-        y[0] = self.y0;
-        for k in 0..self.num_steps {
-            y[k + 1] = y[k] + self.step_size * (self.expression_fn)(self.mesh[k], y[k]);
+        y[0] = y0;
+        let mut g_prev = event.map(|g| g(self.mesh[0], y0));
+        let bound = self.options.divergence_bound.unwrap_or(f64::INFINITY);
+        let stride = progress.as_ref().map_or(self.num_steps, |p| p.stride).max(1);
+
+        let mut chunk_start = 0;
+        while chunk_start < self.num_steps {
+            let chunk_end = (chunk_start + stride).min(self.num_steps);
+            for k in chunk_start..chunk_end {
+                let h = self.mesh[k + 1] - self.mesh[k];  // Local step size
+                y[k + 1] = match self.options.method {
+                    SolverMethod::ForwardEuler => {
+                        y[k] + h * self.eval(self.mesh[k], y[k])
+                    }
+                    SolverMethod::BackwardEuler => self.backward_euler_step(k, y[k])?,
+                };
+
+                // Stop as soon as the iteration blows up instead of exporting inf/NaN
+                if !y[k + 1].is_finite() || y[k + 1].abs() > bound {
+                    return Err(SolverError::Diverged {
+                        step: k + 1,
+                        t: self.mesh[k + 1],
+                        value: y[k + 1],
+                    });
+                }
+
+                if let (Some(g), Some(g_k)) = (event, g_prev) {
+                    let g_next = g(self.mesh[k + 1], y[k + 1]);
+                    if g_k * g_next < 0.0 || (g_next == 0.0 && g_k != 0.0) {
+                        let frac = g_k / (g_k - g_next);  // Linear interpolation of g
+                        let crossing = EventCrossing {
+                            step: k,
+                            t: self.mesh[k] + frac * h,
+                            y: y[k] + frac * (y[k + 1] - y[k]),
+                        };
+                        y.truncate(k + 2);
+                        return Ok((y, Some(crossing)));
+                    }
+                    g_prev = Some(g_next);
+                }
+            }
+
+            if let Some(p) = progress.as_mut()
+                && chunk_end.is_multiple_of(stride)
+                && p.should_emit(Instant::now)
+            {
+                (p.callback)(chunk_end, self.mesh[chunk_end], y[chunk_end]);
+            }
+            chunk_start = chunk_end;
+        }
+        Ok((y, None))
+    }
+
+    /// Solves the same ODE on the same mesh from each initial value in `y0_values`
+    ///
+    /// The parsed expression and mesh are reused for every run. Sweeps always
+    /// cover the whole mesh (the event is not applied), so all trajectories
+    /// line up with `mesh`.
+    ///
+    /// # Arguments
+    /// * `y0_values` - Initial values to start from
+    ///
+    /// # Returns
+    /// * `Result<Sweep, SolverError>` - One trajectory per initial value
+    pub fn solve_sweep(&self, y0_values: &[f64]) -> Result<Sweep, SolverError> {
+        let trajectories = y0_values
+            .iter()
+            .map(|&y0| self.integrate(y0, None, None).map(|(y, _)| y))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Sweep {
+            mesh: self.mesh.clone(),
+            y0_values: y0_values.to_vec(),
+            trajectories,
+        })
+    }
+
+    /// Solves `z = y_k + h*f(t[k+1], z)` for one backward Euler step
+    ///
+    /// Uses Newton's method started from the forward Euler predictor, with
+    /// df/dy approximated by a central finite difference.
+    fn backward_euler_step(&self, k: usize, y_k: f64) -> Result<f64, SolverError> {
+        let f = |t, y| self.eval(t, y);
+        let (t_k, t_next) = (self.mesh[k], self.mesh[k + 1]);
+        let h = t_next - t_k;
+
+        let mut z = y_k + h * f(t_k, y_k);  // Explicit predictor
+        for _ in 0..self.options.max_iterations {
+            let residual = z - y_k - h * f(t_next, z);
+            let delta = f64::EPSILON.cbrt() * z.abs().max(1.0);
+            let dfdy = (f(t_next, z + delta) - f(t_next, z - delta)) / (2.0 * delta);
+            let update = residual / (1.0 - h * dfdy);
+            z -= update;
+            if update.abs() <= self.options.tolerance * z.abs().max(1.0) {
+                return Ok(z);
+            }
         }
-        y
+        Err(SolverError::NonConvergence { step: k, t: t_next })
     }
 
     /// Writes the (t, y) solution pairs to a CSV file
     ///
     /// # Arguments
-    /// * `filename` - Path to output CSV file
+    /// * `filename` - Path to output CSV file, or `-` for standard output
     ///
     /// # Returns
     /// * `Result<(), Box<dyn Error>>` - Ok or descriptive error
     pub fn export_to_csv(&self, filename: &str) -> Result<(), Box<dyn Error>> {
-        let mut writer = csv::Writer::from_path(filename)?;
-        writer.write_record(&["t", "y(t)"])?;
+        self.write_delimited(filename, b',', None, false)
+    }
+
+    /// Writes the (t, y) solution pairs to a tab-separated file
+    ///
+    /// # Arguments
+    /// * `filename` - Path to output TSV file, or `-` for standard output
+    ///
+    /// # Returns
+    /// * `Result<(), Box<dyn Error>>` - Ok or descriptive error
+    pub fn export_to_tsv(&self, filename: &str) -> Result<(), Box<dyn Error>> {
+        self.write_delimited(filename, b'\t', None, false)
+    }
+
+    /// Shared CSV/TSV writer with a `t`, `y(t)` header row
+    ///
+    /// Values use Rust's shortest round-trip formatting unless `precision`
+    /// asks for a fixed number of digits in scientific notation. With
+    /// `include_cumulative`, `cummax_y` and `cummin_y` columns are appended.
+    fn write_delimited(
+        &self,
+        filename: &str,
+        delimiter: u8,
+        precision: Option<usize>,
+        include_cumulative: bool,
+    ) -> Result<(), Box<dyn Error>> {
+        let mut writer = csv::WriterBuilder::new()
+            .delimiter(delimiter)
+            .from_writer(open_output(filename)?);
+        let mut header = vec!["t", "y(t)"];
+        let (cummax, cummin) = if include_cumulative {
+            header.extend(["cummax_y", "cummin_y"]);
+            (self.cumulative_max(), self.cumulative_min())
+        } else {
+            (Vec::new(), Vec::new())
+        };
+        writer.write_record(&header)?;
 
-        for (&t, &y) in self.mesh.iter().zip(self.solution.iter()) {
-            writer.write_record(&[t.to_string(), y.to_string()])?;
+        for (k, (&t, &y)) in self.mesh.iter().zip(self.solution.iter()).enumerate() {
+            let mut record = vec![format_value(t, precision), format_value(y, precision)];
+            if include_cumulative {
+                record.push(format_value(cummax[k], precision));
+                record.push(format_value(cummin[k], precision));
+            }
+            writer.write_record(&record)?;
         }
 
         writer.flush()?;  // Ensure data is written
-        println!("Solution exported to `{}`", filename);
+        report_export(filename);
         Ok(())
     }
-}
 
-// ================================
-// Section: Expression Parser
-// ================================
+    /// Writes the solution and its metadata to a JSON file
+    ///
+    /// The file holds `t_start`, `t_end`, `num_steps`, `expression` and a
+    /// `solution` array of `{"t": ..., "y": ...}` objects, with all values
+    /// stored as JSON numbers.
+    ///
+    /// # Arguments
+    /// * `filename` - Path to output JSON file, or `-` for standard output
+    ///
+    /// # Returns
+    /// * `Result<(), Box<dyn Error>>` - Ok or descriptive error
+    pub fn export_to_json(&self, filename: &str) -> Result<(), Box<dyn Error>> {
+        self.write_json(filename, false)
+    }
 
-/// Parses a string expression like "cos(t) - y" into a callable function
-///
-/// # Arguments
-/// * `expr_str` - String representing the mathematical expression
-///
-/// # Returns
-/// * `Result<Box<dyn Fn(f64, f64) -> f64>, Box<dyn Error>>`
-///   - Function that takes (t, y) and returns f(t, y)
-pub fn parse_expression(
+    /// JSON document writer; `include_cumulative` adds `cummax_y` and
+    /// `cummin_y` to every point
+    fn write_json(&self, filename: &str, include_cumulative: bool) -> Result<(), Box<dyn Error>> {
+        let document = JsonSolution {
+            t_start: self.t_start,
+            t_end: self.t_end,
+            num_steps: self.num_steps,
+            expression: self.expression.as_deref(),
+            warnings: &self.warnings,
+            solution: self.json_points(include_cumulative),
+        };
+
+        let mut writer = open_output(filename)?;
+        serde_json::to_writer_pretty(&mut writer, &document)?;
+        writeln!(writer)?;
+        writer.flush()?;  // Ensure data is written
+        report_export(filename);
+        Ok(())
+    }
+
+    /// Writes the solution as newline-delimited JSON, one `{"t":..,"y":..}`
+    /// object per line, for streaming into log-processing tools
+    ///
+    /// # Arguments
+    /// * `writer` - Destination, e.g. `std::io::stdout()` or a file
+    ///
+    /// # Returns
+    /// * `Result<(), Box<dyn Error>>` - Ok or descriptive error
+    pub fn export_ndjson<W: Write>(&self, writer: W) -> Result<(), Box<dyn Error>> {
+        self.write_ndjson(writer, false)
+    }
+
+    /// NDJSON writer; `include_cumulative` adds `cummax_y` and `cummin_y`
+    /// to every line
+    fn write_ndjson<W: Write>(
+        &self,
+        mut writer: W,
+        include_cumulative: bool,
+    ) -> Result<(), Box<dyn Error>> {
+        for point in self.json_points(include_cumulative) {
+            serde_json::to_writer(&mut writer, &point)?;
+            writeln!(writer)?;
+        }
+        writer.flush()?;  // Ensure data is written
+        Ok(())
+    }
+
+    /// Pairs the mesh and solution as JSON points, optionally with the
+    /// running extremes
+    fn json_points(&self, include_cumulative: bool) -> Vec<JsonPoint> {
+        let (cummax, cummin) = if include_cumulative {
+            (self.cumulative_max(), self.cumulative_min())
+        } else {
+            (Vec::new(), Vec::new())
+        };
+        self.mesh
+            .iter()
+            .zip(self.solution.iter())
+            .enumerate()
+            .map(|(k, (&t, &y))| JsonPoint {
+                t,
+                y,
+                cummax_y: cummax.get(k).copied(),
+                cummin_y: cummin.get(k).copied(),
+            })
+            .collect()
+    }
+
+    /// Writes the mesh and solution values as a raw little-endian binary file
+    /// that can be memory-mapped without parsing
+    ///
+    /// The layout is a 32-byte header followed by the mesh column and then the
+    /// solution column (see `BinarySolution` for the exact offsets).
+    ///
+    /// # Arguments
+    /// * `path` - Path to output binary file
+    ///
+    /// # Returns
+    /// * `Result<(), Box<dyn Error>>` - Ok or descriptive error
+    pub fn export_binary(&self, path: &str) -> Result<(), Box<dyn Error>> {
+        let mut writer = BufWriter::new(OutputFile::create(path)?);
+        writer.write_all(BINARY_MAGIC)?;
+        writer.write_all(&(self.solution.len() as u64).to_le_bytes())?;
+        writer.write_all(&self.t_start.to_le_bytes())?;
+        writer.write_all(&self.t_end.to_le_bytes())?;
+        for value in self.mesh.iter().chain(&self.solution) {
+            writer.write_all(&value.to_le_bytes())?;  // Mesh column, then solution column
+        }
+        writer.flush()?;  // Ensure data is written
+        report_export(path);
+        Ok(())
+    }
+
+    /// Writes the solution to `output.csv_file` in the configured `output.format`
+    ///
+    /// # Arguments
+    /// * `output` - Output section of the solver config
+    ///
+    /// # Returns
+    /// * `Result<(), Box<dyn Error>>` - Ok or descriptive error
+    pub fn export(&self, output: &OutputConfig) -> Result<(), Box<dyn Error>> {
+        self.export_in(output, None)
+    }
+
+    /// Same as `export`, with relative output paths resolved against
+    /// `workdir` (see `resolve_output_path`)
+    ///
+    /// # Arguments
+    /// * `output` - Output section of the solver config
+    /// * `workdir` - Optional base directory for relative paths
+    ///
+    /// # Returns
+    /// * `Result<(), Box<dyn Error>>` - Ok or descriptive error
+    pub fn export_in(
+        &self,
+        output: &OutputConfig,
+        workdir: Option<&Path>,
+    ) -> Result<(), Box<dyn Error>> {
+        let file = &resolve_output_path(&output.csv_file, workdir);
+        match output.format {
+            OutputFormat::Csv => {
+                let delimiter = output.csv_delimiter()?;
+                self.write_delimited(file, delimiter, output.precision, output.include_cumulative)
+            }
+            OutputFormat::Json => self.write_json(file, output.include_cumulative),
+            OutputFormat::Tsv => {
+                self.write_delimited(file, b'\t', output.precision, output.include_cumulative)
+            }
+            OutputFormat::Ndjson => {
+                self.write_ndjson(open_output(file)?, output.include_cumulative)?;
+                report_export(file);
+                Ok(())
+            }
+        }
+    }
+}
+
+// ================================
+// Section: Solver Builder
+// ================================
+
+/// Chainable builder for `EulerSolver1D`
+///
+/// Reads more clearly than the positional `EulerSolver1D::new` and checks
+/// that every required field was set:
+///
+/// ```
+/// use rust_code::{EulerSolver1D, SolverMethod};
+///
+/// let solver = EulerSolver1D::builder()
+///     .expression("cos(t) - y")
+///     .domain(0.0, 10.0)
+///     .steps(100)
+///     .initial(1.0)
+///     .method(SolverMethod::BackwardEuler)
+///     .build()
+///     .unwrap();
+/// assert_eq!(solver.solution.len(), 101);
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct EulerSolver1DBuilder {
+    expression: Option<String>,         // Required: f(t, y) as a string
+    domain: Option<(f64, f64)>,         // Required: (t_start, t_end)
+    steps: Option<usize>,               // Required: number of steps
+    initial: Option<f64>,               // Required: y(t_start)
+    method: SolverMethod,               // Optional: forward Euler by default
+    parameters: HashMap<String, f64>,   // Optional: named constants for the expression
+}
+
+impl EulerSolver1DBuilder {
+    /// Sets the ODE right-hand side, e.g. "cos(t) - y"
+    pub fn expression(mut self, expression: &str) -> Self {
+        self.expression = Some(expression.to_string());
+        self
+    }
+
+    /// Sets the time domain `[t_start, t_end]`
+    pub fn domain(mut self, t_start: f64, t_end: f64) -> Self {
+        self.domain = Some((t_start, t_end));
+        self
+    }
+
+    /// Sets the number of uniform steps
+    pub fn steps(mut self, num_steps: usize) -> Self {
+        self.steps = Some(num_steps);
+        self
+    }
+
+    /// Sets the initial value y(t_start)
+    pub fn initial(mut self, y0: f64) -> Self {
+        self.initial = Some(y0);
+        self
+    }
+
+    /// Sets the time-stepping scheme
+    pub fn method(mut self, method: SolverMethod) -> Self {
+        self.method = method;
+        self
+    }
+
+    /// Sets named constants available to the expression
+    pub fn parameters(mut self, parameters: HashMap<String, f64>) -> Self {
+        self.parameters = parameters;
+        self
+    }
+
+    /// Validates the fields, parses the expression and solves the IVP
+    ///
+    /// # Returns
+    /// * `Result<EulerSolver1D, SolverError>` - Solved system, or
+    ///   `MissingField`, `InvalidExpression` or any solve error
+    pub fn build(self) -> Result<EulerSolver1D, SolverError> {
+        let expression = self.expression.ok_or(SolverError::MissingField("expression"))?;
+        let (t_start, t_end) = self.domain.ok_or(SolverError::MissingField("domain"))?;
+        let num_steps = self.steps.ok_or(SolverError::MissingField("steps"))?;
+        let y0 = self.initial.ok_or(SolverError::MissingField("initial"))?;
+
+        let expression_fn = parse_expression(expression.clone(), &self.parameters)
+            .map_err(|e| SolverError::InvalidExpression(e.to_string()))?;
+        let options = SolverOptions {
+            method: self.method,
+            ..SolverOptions::default()
+        };
+        let mesh = EulerSolver1D::generate_mesh(t_start, t_end, num_steps);
+        let mut solver = EulerSolver1D::with_options(expression_fn, mesh, y0, options)?;
+        solver.expression = Some(expression);
+        Ok(solver)
+    }
+}
+
+impl EulerSolver1D {
+    /// Starts an `EulerSolver1DBuilder`
+    pub fn builder() -> EulerSolver1DBuilder {
+        EulerSolver1DBuilder::default()
+    }
+}
+
+// ================================
+// Section: Adaptive Stepping
+// ================================
+
+/// Settings for `EulerSolver1D::adaptive`
+#[derive(Debug, Clone)]
+pub struct AdaptiveOptions {
+    pub tolerance: f64,         // Maximum accepted local error estimate per step
+    pub initial_step: f64,      // First proposed step size
+    pub min_step: f64,          // Give up when a rejected step would go below this
+    pub max_step: f64,          // Upper limit for proposed step sizes
+    pub debug_trace_path: Option<String>, // Opt-in CSV of every attempt, `-` for stdout
+}
+
+impl Default for AdaptiveOptions {
+    fn default() -> Self {
+        Self {
+            tolerance: 1e-4,
+            initial_step: 1e-2,
+            min_step: 1e-12,
+            max_step: f64::INFINITY,
+            debug_trace_path: None,
+        }
+    }
+}
+
+/// One attempted adaptive step, as written to the debug trace
+#[derive(Debug, Serialize)]
+struct StepAttempt {
+    attempt: usize,             // Running attempt number, rejected ones included
+    t: f64,                     // Start of the attempted step
+    h: f64,                     // Proposed step size
+    error_estimate: f64,        // |two half steps - one full step|
+    accepted: bool,             // Whether the step was kept
+}
+
+impl EulerSolver1D {
+    /// Solves the ODE with step-doubling adaptive forward Euler.
+    ///
+    /// Each attempt compares one step of size `h` with two steps of `h / 2`.
+    /// Their difference estimates the local error. The step is accepted when
+    /// the estimate is within `tolerance`, and the next `h` is scaled by
+    /// `0.9 * sqrt(tolerance / error)`, limited to `[0.2, 5]` times the
+    /// current step. The accepted mesh is then solved with `with_mesh`, so
+    /// `solution` and `warnings` describe that final pass and agree with the
+    /// accepted full steps. `stats.evaluations` and `stats.duration` include
+    /// the step-size search (two evaluations per attempt) as well.
+    ///
+    /// With `options.debug_trace_path` set, every attempt is written to a CSV
+    /// (`attempt,t,h,error_estimate,accepted`), rejected ones included.
+    ///
+    /// # Arguments
+    /// * `expression_fn` - Parsed ODE function (f64, f64) -> f64
+    /// * `t_start`, `t_end` - Time domain bounds, `t_start < t_end`
+    /// * `y0` - Initial y value
+    /// * `options` - Tolerance, step limits and optional trace path
+    ///
+    /// # Returns
+    /// * `Result<Self, SolverError>` - Solver on the accepted mesh, `NonConvergence`
+    ///   if the step size falls below `min_step`, or `Output` if the trace fails
+    pub fn adaptive(
+        expression_fn: impl Fn(f64, f64) -> f64 + 'static,
+        t_start: f64,
+        t_end: f64,
+        y0: f64,
+        options: AdaptiveOptions,
+    ) -> Result<Self, SolverError> {
+        Self::validate_mesh(&[t_start, t_end])?;
+        let started = Instant::now();
+        let evaluations = Cell::new(0);
+        let f = |t, y| {
+            evaluations.set(evaluations.get() + 1);
+            expression_fn(t, y)
+        };
+        let mut mesh = vec![t_start];
+        let mut attempts = Vec::new();
+        let (mut t, mut y) = (t_start, y0);
+        let mut h = options.initial_step.min(options.max_step);
+
+        while t < t_end {
+            let t_next = if t + h >= t_end { t_end } else { t + h };
+            let step = t_next - t;  // Exactly the spacing `with_mesh` will see
+            let slope = f(t, y);    // Shared by the full step and the first half step
+            let full = y + step * slope;
+            let y_mid = y + 0.5 * step * slope;
+            let halves = y_mid + 0.5 * step * f(t + 0.5 * step, y_mid);
+            let error = (halves - full).abs();
+            let error = if error.is_nan() { f64::INFINITY } else { error };  // NaN rejects
+            let accepted = error <= options.tolerance;
+            attempts.push(StepAttempt {
+                attempt: attempts.len(),
+                t,
+                h: step,
+                error_estimate: error,
+                accepted,
+            });
+
+            let factor = if error == 0.0 { 5.0 } else { 0.9 * (options.tolerance / error).sqrt() };
+            h = (step * factor.clamp(0.2, 5.0)).min(options.max_step);
+            if accepted {
+                (t, y) = (t_next, full);
+                mesh.push(t);
+            } else if h < options.min_step {
+                write_trace(options.debug_trace_path.as_deref(), &attempts)?;  // Keep the evidence
+                return Err(SolverError::NonConvergence { step: mesh.len() - 1, t });
+            }
+        }
+
+        write_trace(options.debug_trace_path.as_deref(), &attempts)?;
+        let (search_evaluations, search_duration) = (evaluations.get(), started.elapsed());
+        let mut solver = Self::with_mesh(expression_fn, mesh, y0)?;
+        solver.stats.evaluations += search_evaluations;
+        solver.stats.duration += search_duration;
+        Ok(solver)
+    }
+}
+
+/// Writes the adaptive step attempts to `path` as CSV, if a path is set
+fn write_trace(path: Option<&str>, attempts: &[StepAttempt]) -> Result<(), SolverError> {
+    let Some(path) = path else {
+        return Ok(());
+    };
+    let to_error = |e: &dyn fmt::Display| SolverError::Output(format!("`{}`: {}", path, e));
+    let mut writer = csv::Writer::from_writer(open_output(path).map_err(|e| to_error(&e))?);
+    for attempt in attempts {
+        writer.serialize(attempt).map_err(|e| to_error(&e))?;
+    }
+    writer.flush().map_err(|e| to_error(&e))?;
+    if path != "-" {
+        println!("Debug trace written to `{}`", path);
+    }
+    Ok(())
+}
+
+// ================================
+// Section: Initial Condition Sweeps
+// ================================
+
+/// Trajectories of one ODE from several initial values on a shared mesh
+#[derive(Debug, Clone)]
+pub struct Sweep {
+    pub mesh: Vec<f64>,             // Time points shared by every trajectory
+    pub y0_values: Vec<f64>,        // Initial value of each trajectory
+    pub trajectories: Vec<Vec<f64>>, // One trajectory per initial value, aligned with `mesh`
+}
+
+impl Sweep {
+    /// Writes the sweep to a CSV file with one `y(t)` column per initial value
+    ///
+    /// The header is `t, y0=<value>, ...`.
+    ///
+    /// # Arguments
+    /// * `filename` - Path to output CSV file, or `-` for standard output
+    ///
+    /// # Returns
+    /// * `Result<(), Box<dyn Error>>` - Ok or descriptive error
+    pub fn export_to_csv(&self, filename: &str) -> Result<(), Box<dyn Error>> {
+        self.write_delimited(filename, b',', None)
+    }
+
+    /// Writes the sweep using the `[output]` settings, with relative paths
+    /// resolved against `workdir`
+    ///
+    /// Honours `precision`, and `delimiter` for CSV. Sweeps have no JSON
+    /// layout, so `json` and `ndjson` formats are rejected.
+    ///
+    /// # Arguments
+    /// * `output` - Output section of the config
+    /// * `workdir` - Directory for relative output paths, or `None` for the current one
+    ///
+    /// # Returns
+    /// * `Result<(), Box<dyn Error>>` - Ok or descriptive error
+    pub fn export_in(
+        &self,
+        output: &OutputConfig,
+        workdir: Option<&Path>,
+    ) -> Result<(), Box<dyn Error>> {
+        let file = &resolve_output_path(&output.csv_file, workdir);
+        match output.format {
+            OutputFormat::Csv => {
+                self.write_delimited(file, output.csv_delimiter()?, output.precision)
+            }
+            OutputFormat::Tsv => self.write_delimited(file, b'\t', output.precision),
+            OutputFormat::Json | OutputFormat::Ndjson => {
+                Err("sweeps can only be exported as CSV or TSV".into())
+            }
+        }
+    }
+
+    /// Shared CSV/TSV writer, formatting values like `EulerSolver1D` exports
+    fn write_delimited(
+        &self,
+        filename: &str,
+        delimiter: u8,
+        precision: Option<usize>,
+    ) -> Result<(), Box<dyn Error>> {
+        let mut writer = csv::WriterBuilder::new()
+            .delimiter(delimiter)
+            .from_writer(open_output(filename)?);
+
+        let mut header = vec!["t".to_string()];
+        header.extend(self.y0_values.iter().map(|y0| format!("y0={}", y0)));
+        writer.write_record(&header)?;
+
+        for (k, &t) in self.mesh.iter().enumerate() {
+            let mut row = vec![format_value(t, precision)];
+            row.extend(self.trajectories.iter().map(|y| format_value(y[k], precision)));
+            writer.write_record(&row)?;
+        }
+
+        writer.flush()?;  // Ensure data is written
+        report_export(filename);
+        Ok(())
+    }
+}
+
+// ================================
+// Section: Solution Analysis
+// ================================
+
+impl EulerSolver1D {
+    /// Finds the first time the solution leaves the interval `[lo, hi]`
+    ///
+    /// The exit time is linearly interpolated between the last mesh point
+    /// inside the interval and the first one outside it. If the initial value
+    /// is already outside, `t_start` is returned.
+    ///
+    /// # Arguments
+    /// * `lo`, `hi` - Interval bounds, `lo <= hi`
+    ///
+    /// # Returns
+    /// * `Option<f64>` - Exit time, or `None` if the solution stays within bounds
+    pub fn first_exit_time(&self, lo: f64, hi: f64) -> Option<f64> {
+        let inside = |y: f64| (lo..=hi).contains(&y);
+        if !inside(self.solution[0]) {
+            return Some(self.mesh[0]);
+        }
+
+        let k = self.solution.windows(2).position(|w| !inside(w[1]))?;
+        let (y_k, y_next) = (self.solution[k], self.solution[k + 1]);
+        let bound = if y_next > hi { hi } else { lo };  // Boundary that was crossed
+        let frac = (bound - y_k) / (y_next - y_k);
+        Some(self.mesh[k] + frac * (self.mesh[k + 1] - self.mesh[k]))
+    }
+
+    /// Computes the rise time between two fractions of the final value
+    ///
+    /// The levels are `y0 + frac * (y_final - y0)` for `frac` = `low` and
+    /// `high` (e.g. 0.1 and 0.9), so responses with a non-zero initial value
+    /// are handled. Both first crossings are linearly interpolated, and the
+    /// result is their difference.
+    ///
+    /// # Arguments
+    /// * `low`, `high` - Fractions of the final value, `0 <= low < high <= 1`
+    ///
+    /// # Returns
+    /// * `Option<f64>` - Rise time, or `None` if the fractions are invalid, the
+    ///   solution does not rise, or it decreases anywhere before reaching `high`
+    pub fn rise_time(&self, low: f64, high: f64) -> Option<f64> {
+        let (y0, y_final) = (self.solution[0], *self.solution.last()?);
+        let rising = y_final > y0;          // Also false for NaN values
+        if !(0.0 <= low && low < high && high <= 1.0 && rising) {
+            return None;
+        }
+        let level = |frac: f64| y0 + frac * (y_final - y0);
+        let k_high = self.solution.iter().position(|&y| y >= level(high))?;
+        if self.solution[..=k_high].windows(2).any(|w| w[1] < w[0]) {
+            return None;            // Non-monotone rise
+        }
+
+        let crossing = |target: f64| -> f64 {
+            let k = self.solution.iter().position(|&y| y >= target).unwrap_or(k_high);
+            if k == 0 {
+                return self.mesh[0];
+            }
+            let (y_prev, y_k) = (self.solution[k - 1], self.solution[k]);
+            let frac = (target - y_prev) / (y_k - y_prev);
+            self.mesh[k - 1] + frac * (self.mesh[k] - self.mesh[k - 1])
+        };
+        Some(crossing(level(high)) - crossing(level(low)))
+    }
+
+    /// Computes the arc length of the piecewise-linear (t, y) curve,
+    /// `sum sqrt(dt^2 + dy^2)` over all mesh segments
+    ///
+    /// Each segment uses its own `dt`, so non-uniform meshes are handled.
+    pub fn arc_length(&self) -> f64 {
+        self.mesh
+            .windows(2)
+            .zip(self.solution.windows(2))
+            .map(|(t, y)| (t[1] - t[0]).hypot(y[1] - y[0]))
+            .sum()
+    }
+
+    /// Samples the solution once per forcing period (a Poincaré section)
+    ///
+    /// Returns `y` at every `t = phase + k * period` (integer `k`, negative
+    /// allowed) that lies within the mesh, in increasing order of `t`.
+    ///
+    /// # Arguments
+    /// * `period` - Forcing period, must be positive
+    /// * `phase` - Time offset of the section
+    ///
+    /// # Returns
+    /// * `Vec<f64>` - Linearly interpolated section values (empty if `period <= 0`)
+    pub fn poincare_section(&self, period: f64, phase: f64) -> Vec<f64> {
+        if !(period > 0.0 && period.is_finite()) {
+            return Vec::new();
+        }
+        let (t_first, t_last) = (self.mesh[0], self.mesh[self.mesh.len() - 1]);
+        let k_first = ((t_first - phase) / period).ceil() as i64;
+        (k_first..)
+            .map(|k| phase + k as f64 * period)
+            .take_while(|&t| t <= t_last)
+            .filter_map(|t| self.interpolate_at(t))
+            .collect()
+    }
+
+    /// Resamples the solution onto a uniform grid whose length is a power of
+    /// two, as expected by radix-2 FFTs
+    ///
+    /// The grid spans the whole mesh, endpoints included, and values are
+    /// linearly interpolated, so non-uniform meshes are handled.
+    ///
+    /// # Arguments
+    /// * `min_len` - Minimum number of samples; rounded up to the next power of two
+    ///   (at least 2)
+    ///
+    /// # Returns
+    /// * `(Vec<f64>, Vec<f64>)` - Uniform sample times and resampled values
+    pub fn resample_pow2(&self, min_len: usize) -> (Vec<f64>, Vec<f64>) {
+        let len = min_len.max(2).next_power_of_two();
+        let (t_first, t_last) = (self.mesh[0], self.mesh[self.mesh.len() - 1]);
+        let dt = (t_last - t_first) / (len - 1) as f64;
+        let times: Vec<f64> = (0..len)
+            .map(|i| if i == len - 1 { t_last } else { t_first + i as f64 * dt })
+            .collect();
+        let values = times.iter().map(|&t| self.interpolate_clamped(t)).collect();
+        (times, values)
+    }
+
+    /// Running maximum `max(y[0..=k])` at every mesh point (peak hold)
+    pub fn cumulative_max(&self) -> Vec<f64> {
+        self.running(f64::max)
+    }
+
+    /// Running minimum `min(y[0..=k])` at every mesh point
+    pub fn cumulative_min(&self) -> Vec<f64> {
+        self.running(f64::min)
+    }
+
+    /// Folds the solution with `pick`, keeping every intermediate result
+    fn running(&self, pick: fn(f64, f64) -> f64) -> Vec<f64> {
+        self.solution
+            .iter()
+            .scan(self.solution[0], |acc, &y| {
+                *acc = pick(*acc, y);
+                Some(*acc)
+            })
+            .collect()
+    }
+
+    /// Linearly interpolates the solution at `t`, or `None` outside the mesh
+    fn interpolate_at(&self, t: f64) -> Option<f64> {
+        let (t_first, t_last) = (self.mesh[0], self.mesh[self.mesh.len() - 1]);
+        (t_first..=t_last).contains(&t).then(|| self.interpolate_clamped(t))
+    }
+
+    /// Linearly interpolates the solution on the mesh segment containing `t`
+    ///
+    /// Outside the mesh the end segment is extended linearly, which only
+    /// matters for times off by rounding.
+    fn interpolate_clamped(&self, t: f64) -> f64 {
+        let k = self.mesh.partition_point(|&m| m <= t).clamp(1, self.mesh.len() - 1) - 1;
+        let frac = (t - self.mesh[k]) / (self.mesh[k + 1] - self.mesh[k]);
+        self.solution[k] + frac * (self.solution[k + 1] - self.solution[k])
+    }
+}
+
+// ================================
+// Section: ND Systems
+// ================================
+
+/// Forward Euler solver for N-dimensional systems dy/dt = f(t, y)
+pub struct EulerSolverND {
+    pub system_fn: SystemFunction, // Evaluated system function, returns one value per component
+    pub t_start: f64,          // Domain start
+    pub t_end: f64,            // Domain end
+    pub y0: Vec<f64>,          // Initial state
+    pub num_steps: usize,      // Number of steps
+    pub mesh: Vec<f64>,        // Discretized mesh of time points
+    pub step_size: f64,        // Time step size
+    pub solution: Vec<Vec<f64>>, // Computed state at each mesh point
+    pub clamps: Vec<Option<(f64, f64)>>, // Optional `(lo, hi)` bounds per component
+    pub clamp_counts: Vec<usize>, // Number of steps each component was clamped
+}
+
+impl EulerSolverND {
+    /// Constructs a new ND Euler solver on a uniform mesh and computes the solution.
+    ///
+    /// # Arguments
+    /// * `system_fn` - System function (t, &y) -> dy/dt, same length as `y0`
+    /// * `t_start`, `t_end` - Time domain bounds
+    /// * `y0` - Initial state
+    /// * `num_steps` - Number of steps (mesh resolution)
+    ///
+    /// # Returns
+    /// * `Self` - Solver object with computed mesh and solution
+    pub fn new(
+        system_fn: impl Fn(f64, &[f64]) -> Vec<f64> + 'static,
+        t_start: f64,
+        t_end: f64,
+        y0: Vec<f64>,
+        num_steps: usize,
+    ) -> Self {
+        Self::with_clamps(system_fn, t_start, t_end, y0, num_steps, Vec::new())
+    }
+
+    /// Constructs an ND solver that keeps selected components within bounds.
+    ///
+    /// After every step, component `i` is clamped to `clamps[i] = Some((lo, hi))`
+    /// (use infinite bounds for one-sided limits); `None` or a missing entry
+    /// leaves it unbounded. `clamp_counts[i]` reports how many steps moved it.
+    ///
+    /// # Arguments
+    /// * `system_fn`, `t_start`, `t_end`, `y0`, `num_steps` - As for `new`
+    /// * `clamps` - Optional bounds per component
+    ///
+    /// # Returns
+    /// * `Self` - Solver object with computed mesh, solution and clamp counts
+    pub fn with_clamps(
+        system_fn: impl Fn(f64, &[f64]) -> Vec<f64> + 'static,
+        t_start: f64,
+        t_end: f64,
+        y0: Vec<f64>,
+        num_steps: usize,
+        mut clamps: Vec<Option<(f64, f64)>>,
+    ) -> Self {
+        clamps.resize(y0.len(), None);
+        let mut solver = Self {
+            system_fn: Box::new(system_fn),
+            t_start,
+            t_end,
+            y0,
+            num_steps,
+            mesh: EulerSolver1D::generate_mesh(t_start, t_end, num_steps),
+            step_size: (t_end - t_start) / num_steps as f64,
+            solution: Vec::new(),
+            clamp_counts: vec![0; clamps.len()],
+            clamps,
+        };
+        let (solution, clamp_counts) = solver.solve();  // Run computation
+        solver.solution = solution;
+        solver.clamp_counts = clamp_counts;
+        solver
+    }
+
+    /// Number of state components
+    pub fn dimension(&self) -> usize {
+        self.y0.len()
+    }
+
+    /// Solves the system using the forward Euler method, component-wise,
+    /// applying the clamps after each step and counting how often they bite
+    fn solve(&self) -> (Vec<Vec<f64>>, Vec<usize>) {
+        let mut y = Vec::with_capacity(self.num_steps + 1);
+        let mut counts = vec![0; self.clamps.len()];
+        y.push(self.y0.clone());
+        for k in 0..self.num_steps {
+            let h = self.mesh[k + 1] - self.mesh[k];
+            let slope = (self.system_fn)(self.mesh[k], &y[k]);
+            let mut next: Vec<f64> =
+                y[k].iter().zip(slope.iter()).map(|(&y_i, &f_i)| y_i + h * f_i).collect();
+            for ((value, clamp), count) in next.iter_mut().zip(&self.clamps).zip(&mut counts) {
+                if let Some((lo, hi)) = *clamp {
+                    let clamped = value.max(lo).min(hi);
+                    if clamped != *value {
+                        *value = clamped;
+                        *count += 1;
+                    }
+                }
+            }
+            y.push(next);
+        }
+        (y, counts)
+    }
+
+    /// Computes the nullclines of a 2D system by scanning a grid for sign changes
+    ///
+    /// The system is evaluated at `t_start`, so this describes the phase
+    /// portrait of autonomous systems. Zeros are linearly interpolated along
+    /// grid edges; grid nodes where a component is exactly zero are included.
+    ///
+    /// # Arguments
+    /// * `x_range` - `(min, max)` of the first state component
+    /// * `y_range` - `(min, max)` of the second state component
+    /// * `resolution` - Number of grid cells per axis
+    ///
+    /// # Returns
+    /// * `(PhasePoints, PhasePoints)` - Points where f0 = 0 and where f1 = 0
+    ///   (both empty if the system is not two-dimensional)
+    pub fn nullclines(
+        &self,
+        x_range: (f64, f64),
+        y_range: (f64, f64),
+        resolution: usize,
+    ) -> (PhasePoints, PhasePoints) {
+        if self.dimension() != 2 || resolution == 0 {
+            return (Vec::new(), Vec::new());
+        }
+
+        let xs = EulerSolver1D::generate_mesh(x_range.0, x_range.1, resolution);
+        let ys = EulerSolver1D::generate_mesh(y_range.0, y_range.1, resolution);
+        let values: Vec<Vec<Vec<f64>>> = xs
+            .iter()
+            .map(|&x| ys.iter().map(|&y| (self.system_fn)(self.t_start, &[x, y])).collect())
+            .collect();
+
+        (
+            zero_set_on_grid(&xs, &ys, |i, j| values[i][j][0]),
+            zero_set_on_grid(&xs, &ys, |i, j| values[i][j][1]),
+        )
+    }
+
+    /// Estimates the largest Lyapunov exponent along the computed trajectory
+    ///
+    /// Uses tangent-vector renormalization. A unit tangent vector `v` is
+    /// advanced with the linearized Euler map `v + h * J(t_k, y_k) v`, where
+    /// `J v` is a central finite difference of `system_fn` along `v`. `v` is
+    /// renormalized after every step, and the exponent is the accumulated
+    /// `ln |v|` divided by the elapsed time. Negative values indicate
+    /// contraction, values near zero neutral dynamics and positive values
+    /// sensitive dependence on initial conditions.
+    ///
+    /// # Returns
+    /// * `f64` - Exponent estimate of the discrete Euler map (NaN for an empty system
+    ///   or an empty time domain)
+    pub fn max_lyapunov(&self) -> f64 {
+        let n = self.dimension();
+        let elapsed = self.mesh[self.num_steps] - self.mesh[0];
+        if n == 0 || elapsed <= 0.0 {
+            return f64::NAN;
+        }
+
+        let mut v = vec![1.0 / (n as f64).sqrt(); n];  // Unit vector along the diagonal
+        let mut log_growth = 0.0;
+        for k in 0..self.num_steps {
+            let (t_k, y_k) = (self.mesh[k], &self.solution[k]);
+            let h = self.mesh[k + 1] - t_k;
+            let scale = y_k.iter().fold(1.0f64, |m, y| m.max(y.abs()));
+            let delta = f64::EPSILON.cbrt() * scale;
+            let shifted = |sign: f64| -> Vec<f64> {
+                let y: Vec<f64> = y_k.iter().zip(&v).map(|(y, v)| y + sign * delta * v).collect();
+                (self.system_fn)(t_k, &y)
+            };
+            let (plus, minus) = (shifted(1.0), shifted(-1.0));
+            for i in 0..n {
+                v[i] += h * (plus[i] - minus[i]) / (2.0 * delta);
+            }
+
+            let norm = v.iter().map(|x| x * x).sum::<f64>().sqrt();
+            log_growth += norm.ln();
+            v.iter_mut().for_each(|x| *x /= norm);
+        }
+        log_growth / elapsed
+    }
+}
+
+/// Collects the zero set of a grid function, interpolating sign changes
+/// along each edge and including nodes that are exactly zero
+fn zero_set_on_grid(xs: &[f64], ys: &[f64], value: impl Fn(usize, usize) -> f64) -> PhasePoints {
+    let mut points = Vec::new();
+    for i in 0..xs.len() {
+        for j in 0..ys.len() {
+            let v = value(i, j);
+            if v == 0.0 {
+                points.push((xs[i], ys[j]));
+                continue;
+            }
+            // Edge to the right (along x)
+            if i + 1 < xs.len() {
+                let v_next = value(i + 1, j);
+                if v * v_next < 0.0 {
+                    let frac = v / (v - v_next);
+                    points.push((xs[i] + frac * (xs[i + 1] - xs[i]), ys[j]));
+                }
+            }
+            // Edge upward (along y)
+            if j + 1 < ys.len() {
+                let v_next = value(i, j + 1);
+                if v * v_next < 0.0 {
+                    let frac = v / (v - v_next);
+                    points.push((xs[i], ys[j] + frac * (ys[j + 1] - ys[j])));
+                }
+            }
+        }
+    }
+    points
+}
+
+// ================================
+// Section: Expression Parser
+// ================================
+
+/// Constants and functions built into `meval`, which parameters may not shadow
+const BUILTIN_NAMES: &[&str] = &[
+    "pi", "e", "sqrt", "exp", "ln", "abs", "sin", "cos", "tan", "asin", "acos", "atan", "atan2",
+    "sinh", "cosh", "tanh", "asinh", "acosh", "atanh", "floor", "ceil", "round", "signum", "max",
+    "min",
+];
+
+/// Rejects parameter names that would shadow a variable in `variables` or a builtin
+fn check_parameter_names(
+    parameters: &HashMap<String, f64>,
+    variables: &[&str],
+) -> Result<(), Box<dyn Error>> {
+    for name in parameters.keys() {
+        if variables.contains(&name.as_str()) {
+            return Err(format!("Parameter `{}` collides with an ODE variable", name).into());
+        }
+        if BUILTIN_NAMES.contains(&name.as_str()) {
+            return Err(format!("Parameter `{}` collides with a builtin", name).into());
+        }
+    }
+    Ok(())
+}
+
+/// Parses a string expression like "cos(t) - y" into a callable function
+///
+/// The expression is bound to `t` and `y` once, so each evaluation only
+/// fills in the two variable slots instead of rebuilding a `Context`.
+/// Named parameters such as `k` in "-k*y" are registered as constants
+/// alongside the builtins; names of variables or builtins such as `pi` are
+/// rejected. Unknown variables or functions are reported here rather than
+/// at evaluation time.
+///
+/// # Arguments
+/// * `expr_str` - String representing the mathematical expression
+/// * `parameters` - Named constants available to the expression
+///
+/// # Returns
+/// * `Result<OdeFunction, Box<dyn Error>>`
+///   - Function that takes (t, y) and returns f(t, y)
+pub fn parse_expression(
     expr_str: String,
-) -> Result<Box<dyn Fn(f64, f64) -> f64 + 'static>, Box<dyn Error>> {
+    parameters: &HashMap<String, f64>,
+) -> Result<OdeFunction, Box<dyn Error>> {
+    check_parameter_names(parameters, &["t", "y"])?;
     let expr = expr_str.parse::<Expr>()?;  // Parse using `meval`
+    let f = expr.bind2_with_context(parameter_context(parameters), "t", "y")?;
+    Ok(Box::new(f))
+}
+
+/// Builds a `meval` context with the builtins plus the given parameters
+fn parameter_context(parameters: &HashMap<String, f64>) -> Context<'static> {
+    let mut ctx = Context::new();          // Builtin constants and functions
+    for (name, &value) in parameters {
+        ctx.var(name.as_str(), value);
+    }
+    ctx
+}
+
+/// Single instruction of a compiled Reverse Polish Notation program
+#[derive(Debug, Clone, Copy)]
+enum RpnToken {
+    Const(f64),                     // Literal number or named constant
+    VarT,                           // Push the current time `t`
+    VarY,                           // Push the current value `y`
+    Unary(fn(f64) -> f64),          // Pops one operand, pushes the result
+    Binary(fn(f64, f64) -> f64),    // Pops two operands, pushes the result
+}
+
+/// Parses a Reverse Polish Notation token list like `["t", "cos", "y", "-"]`
+/// into a callable function, as an alternative to infix `meval` expressions.
+///
+/// Supported tokens are numbers, the variables `t` and `y`, the constants
+/// `pi` and `e`, the operators `+ - * / ^`, `neg` for unary minus, the
+/// functions `sqrt exp ln abs sin cos tan`, and names from `parameters`.
+/// The token list is validated once here, so evaluation itself cannot fail.
+///
+/// # Arguments
+/// * `tokens` - RPN tokens, operands before their operator
+/// * `parameters` - Named constants available as tokens
+///
+/// # Returns
+/// * `Result<OdeFunction, Box<dyn Error>>`
+///   - Function that takes (t, y) and returns f(t, y)
+pub fn parse_rpn(
+    tokens: &[&str],
+    parameters: &HashMap<String, f64>,
+) -> Result<OdeFunction, Box<dyn Error>> {
+    check_parameter_names(parameters, &["t", "y", "neg"])?;
+    let mut program = Vec::with_capacity(tokens.len());
+    let mut depth: usize = 0;       // Stack depth after each token
+    let mut max_depth: usize = 0;
+
+    for &token in tokens {
+        let op = match token {
+            "t" => RpnToken::VarT,
+            "y" => RpnToken::VarY,
+            "pi" => RpnToken::Const(std::f64::consts::PI),
+            "e" => RpnToken::Const(std::f64::consts::E),
+            "+" => RpnToken::Binary(|a, b| a + b),
+            "-" => RpnToken::Binary(|a, b| a - b),
+            "*" => RpnToken::Binary(|a, b| a * b),
+            "/" => RpnToken::Binary(|a, b| a / b),
+            "^" => RpnToken::Binary(f64::powf),
+            "neg" => RpnToken::Unary(|a| -a),
+            "sqrt" => RpnToken::Unary(f64::sqrt),
+            "exp" => RpnToken::Unary(f64::exp),
+            "ln" => RpnToken::Unary(f64::ln),
+            "abs" => RpnToken::Unary(f64::abs),
+            "sin" => RpnToken::Unary(f64::sin),
+            "cos" => RpnToken::Unary(f64::cos),
+            "tan" => RpnToken::Unary(f64::tan),
+            other => match parameters.get(other) {
+                Some(&value) => RpnToken::Const(value),
+                None => RpnToken::Const(
+                    other
+                        .parse::<f64>()
+                        .map_err(|_| format!("Unknown RPN token `{}`", other))?,
+                ),
+            },
+        };
+
+        let (pops, pushes) = match op {
+            RpnToken::Const(_) | RpnToken::VarT | RpnToken::VarY => (0, 1),
+            RpnToken::Unary(_) => (1, 1),
+            RpnToken::Binary(_) => (2, 1),
+        };
+        if depth < pops {
+            return Err(format!("RPN token `{}` is missing operands", token).into());
+        }
+        depth = depth - pops + pushes;
+        max_depth = max_depth.max(depth);
+        program.push(op);
+    }
+
+    if depth != 1 {
+        return Err(
+            format!("RPN expression leaves {} values on the stack, expected 1", depth).into(),
+        );
+    }
+
     let f = move |t: f64, y: f64| {
-        let mut ctx = Context::new();
-        ctx.var("t", t);
-        ctx.var("y", y);
-        expr.eval_with_context(ctx).unwrap()  // Evaluate with context
+        let mut stack: Vec<f64> = Vec::with_capacity(max_depth);
+        for op in &program {
+            // Operand counts were checked above, so the pops cannot fail
+            let value = match *op {
+                RpnToken::Const(c) => c,
+                RpnToken::VarT => t,
+                RpnToken::VarY => y,
+                RpnToken::Unary(g) => {
+                    let a = stack.pop().unwrap();
+                    g(a)
+                }
+                RpnToken::Binary(g) => {
+                    let b = stack.pop().unwrap();
+                    let a = stack.pop().unwrap();
+                    g(a, b)
+                }
+            };
+            stack.push(value);
+        }
+        stack[0]
     };
     Ok(Box::new(f))
 }
 
+// ================================
+// Section: Control Inputs
+// ================================
+
+/// Piecewise-constant control input u(t) defined by `(time, value)` breakpoints
+///
+/// Each value is held from its breakpoint until the next one (zero-order
+/// hold). Before the first breakpoint the first value is used.
+#[derive(Debug, Clone)]
+pub struct ControlSchedule {
+    points: Vec<(f64, f64)>,    // Breakpoints sorted by strictly increasing time
+}
+
+impl ControlSchedule {
+    /// Creates a schedule from `(time, value)` breakpoints
+    ///
+    /// # Arguments
+    /// * `points` - Non-empty list of breakpoints with strictly increasing times
+    ///
+    /// # Returns
+    /// * `Result<Self, Box<dyn Error>>` - Schedule or descriptive error
+    pub fn new(points: Vec<(f64, f64)>) -> Result<Self, Box<dyn Error>> {
+        if points.is_empty() {
+            return Err("Control schedule needs at least one breakpoint".into());
+        }
+        if points.windows(2).any(|w| w[1].0 <= w[0].0) {
+            return Err("Control schedule times must be strictly increasing".into());
+        }
+        Ok(Self { points })
+    }
+
+    /// Returns the control value held at time `t`
+    pub fn value_at(&self, t: f64) -> f64 {
+        let idx = self.points.partition_point(|&(time, _)| time <= t);
+        self.points[idx.saturating_sub(1)].1
+    }
+}
+
+/// Parses an expression in `t`, `y` and the control input `u`, such as
+/// "u - y", and binds `u` to the given schedule.
+///
+/// # Arguments
+/// * `expr_str` - String representing f(t, y, u)
+/// * `schedule` - Control schedule supplying u(t)
+/// * `parameters` - Named constants available to the expression, as for
+///   `parse_expression`
+///
+/// # Returns
+/// * `Result<OdeFunction, Box<dyn Error>>`
+///   - Function that takes (t, y) and returns f(t, y, u(t))
+pub fn parse_controlled_expression(
+    expr_str: String,
+    schedule: ControlSchedule,
+    parameters: &HashMap<String, f64>,
+) -> Result<OdeFunction, Box<dyn Error>> {
+    check_parameter_names(parameters, &["t", "y", "u"])?;
+    let expr = expr_str.parse::<Expr>()?;
+    let f = expr.bind3_with_context(parameter_context(parameters), "t", "y", "u")?;
+    Ok(Box::new(move |t, y| f(t, y, schedule.value_at(t))))
+}
+
+// ================================
+// Section: Stochastic Solver (Euler-Maruyama)
+// ================================
+
+/// Trajectories of an SDE ensemble sharing a common mesh
+#[derive(Debug, Clone)]
+pub struct SdeEnsemble {
+    pub mesh: Vec<f64>,             // Time points shared by every path
+    pub paths: Vec<Vec<f64>>,       // One trajectory per path, aligned with `mesh`
+}
+
+impl SdeEnsemble {
+    /// Computes the ensemble mean and (population) variance at each mesh point
+    ///
+    /// # Returns
+    /// * `(Vec<f64>, Vec<f64>)` - Mean and variance per time point
+    pub fn mean_and_variance(&self) -> (Vec<f64>, Vec<f64>) {
+        let n_paths = self.paths.len() as f64;
+        let mut mean = vec![0.0; self.mesh.len()];
+        let mut variance = vec![0.0; self.mesh.len()];
+        for k in 0..self.mesh.len() {
+            mean[k] = self.paths.iter().map(|path| path[k]).sum::<f64>() / n_paths;
+            variance[k] = self
+                .paths
+                .iter()
+                .map(|path| (path[k] - mean[k]).powi(2))
+                .sum::<f64>()
+                / n_paths;
+        }
+        (mean, variance)
+    }
+}
+
+/// Creates the random number generator used by every randomized API
+///
+/// All stochastic functions in this crate take an explicit `seed: u64` and
+/// build their generator here; nothing draws from OS entropy or a thread-local
+/// RNG. Passing the same seed therefore reproduces a whole run exactly.
+/// Features that need several independent streams derive them from the one
+/// seed instead of asking for more.
+///
+/// # Arguments
+/// * `seed` - Seed of the generator
+///
+/// # Returns
+/// * `StdRng` - Deterministically seeded generator
+pub fn seeded_rng(seed: u64) -> StdRng {
+    StdRng::seed_from_u64(seed)
+}
+
+/// Seed of the sub-stream `stream` of a run seeded with `seed`
+///
+/// Seed and stream index are hashed together with SplitMix64, so nearby run
+/// seeds (42 and 43) do not share streams the way `seed + stream` would.
+fn derive_seed(seed: u64, stream: u64) -> u64 {
+    splitmix64(splitmix64(seed) ^ stream)
+}
+
+/// SplitMix64 finalizer, a bijective mix with good avalanche behaviour
+fn splitmix64(x: u64) -> u64 {
+    let mut z = x.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Draws a standard normal sample using the Box-Muller transform
+fn standard_normal(rng: &mut StdRng) -> f64 {
+    let u1: f64 = 1.0 - rng.r#gen::<f64>();  // In (0, 1], keeps ln finite
+    let u2: f64 = rng.r#gen::<f64>();
+    (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
+}
+
+/// Solves dy = a(t, y) dt + b(t, y) dW along one sample path using the
+/// Euler-Maruyama method.
+///
+/// # Arguments
+/// * `drift` - Drift term a(t, y)
+/// * `diffusion` - Diffusion term b(t, y)
+/// * `mesh` - Strictly increasing time points, at least two
+/// * `y0` - Initial y value at `mesh[0]`
+/// * `seed` - Seed for the Brownian increments; equal seeds give equal paths
+///
+/// # Returns
+/// * `Result<Vec<f64>, SolverError>` - Path values at the mesh points
+pub fn solve_sde(
+    drift: impl Fn(f64, f64) -> f64,
+    diffusion: impl Fn(f64, f64) -> f64,
+    mesh: &[f64],
+    y0: f64,
+    seed: u64,
+) -> Result<Vec<f64>, SolverError> {
+    EulerSolver1D::validate_mesh(mesh)?;
+    Ok(euler_maruyama_path(&drift, &diffusion, mesh, y0, seed))
+}
+
+/// Solves an ensemble of `n_paths` Euler-Maruyama paths, where path `i` is
+/// seeded from a hash of `seed` and `i` so the whole ensemble is reproducible
+/// and ensembles with different seeds are independent.
+///
+/// # Arguments
+/// * `drift` - Drift term a(t, y)
+/// * `diffusion` - Diffusion term b(t, y)
+/// * `y0` - Initial y value shared by every path
+/// * `n_paths` - Number of sample paths
+/// * `seed` - Seed of the run; path `i` uses sub-stream `i`
+/// * `mesh` - Strictly increasing time points, at least two
+///
+/// # Returns
+/// * `Result<SdeEnsemble, SolverError>` - All paths on the shared mesh
+pub fn solve_sde_ensemble(
+    drift: impl Fn(f64, f64) -> f64,
+    diffusion: impl Fn(f64, f64) -> f64,
+    y0: f64,
+    n_paths: usize,
+    seed: u64,
+    mesh: &[f64],
+) -> Result<SdeEnsemble, SolverError> {
+    EulerSolver1D::validate_mesh(mesh)?;
+    let paths = (0..n_paths)
+        .map(|i| euler_maruyama_path(&drift, &diffusion, mesh, y0, derive_seed(seed, i as u64)))
+        .collect();
+    Ok(SdeEnsemble {
+        mesh: mesh.to_vec(),
+        paths,
+    })
+}
+
+/// Euler-Maruyama loop for a single path on an already validated mesh
+fn euler_maruyama_path(
+    drift: &dyn Fn(f64, f64) -> f64,
+    diffusion: &dyn Fn(f64, f64) -> f64,
+    mesh: &[f64],
+    y0: f64,
+    seed: u64,
+) -> Vec<f64> {
+    let mut rng = seeded_rng(seed);
+    let mut y = vec![0.0; mesh.len()];
+    y[0] = y0;
+    for k in 0..mesh.len() - 1 {
+        let (t, h) = (mesh[k], mesh[k + 1] - mesh[k]);
+        let dw = h.sqrt() * standard_normal(&mut rng);  // Brownian increment ~ N(0, h)
+        y[k + 1] = y[k] + h * drift(t, y[k]) + diffusion(t, y[k]) * dw;
+    }
+    y
+}
+
+// ================================
+// Section: Convergence Study
+// ================================
+
+/// Runs the same IVP at `num_steps`, `2 * num_steps`, `4 * num_steps`, ...
+/// and measures the error at the final time against an exact solution.
+///
+/// Forward Euler shorthand for `convergence_table`, reporting each level's
+/// `final_error` together with the observed order.
+///
+/// # Arguments
+/// * `expression_fn` - Shared ODE function, reused by every refinement level
+/// * `t_start`, `t_end` - Time domain bounds
+/// * `y0` - Initial y value
+/// * `num_steps` - Number of steps at the coarsest level
+/// * `exact_fn` - Exact solution y(t) used to compute the error
+/// * `refinements` - Number of times the step size is halved
+///
+/// # Returns
+/// * `Result<Vec<(usize, f64, f64)>, SolverError>` - `(num_steps, error, estimated_order)`
+///   per level, where the order is `log2(err_prev / err_curr)` (NaN for the first level)
+pub fn convergence_study(
+    expression_fn: Rc<dyn Fn(f64, f64) -> f64>,
+    t_start: f64,
+    t_end: f64,
+    y0: f64,
+    num_steps: usize,
+    exact_fn: impl Fn(f64) -> f64,
+    refinements: usize,
+) -> Result<Vec<(usize, f64, f64)>, SolverError> {
+    let build = |n: usize| {
+        let f = Rc::clone(&expression_fn);
+        EulerSolver1D::new(move |t, y| f(t, y), t_start, t_end, y0, n)
+    };
+    let rows = convergence_table(build, exact_fn, num_steps, refinements)?;
+
+    let orders = std::iter::once(f64::NAN)
+        .chain(rows.windows(2).map(|w| (w[0].final_error / w[1].final_error).log2()));
+    Ok(rows
+        .iter()
+        .zip(orders)
+        .map(|(row, order)| (row.num_steps, row.final_error, order))
+        .collect())
+}
+
+/// One refinement level of a `convergence_table`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ConvergenceRow {
+    pub num_steps: usize,       // Number of steps at this level
+    pub step_size: f64,         // Mean step size at this level
+    pub max_error: f64,         // Max |y - y_exact| over the mesh
+    pub final_error: f64,       // |y - y_exact| at the last mesh point
+    pub ratio: f64,             // Previous max_error / this max_error (NaN for the first level)
+}
+
+/// Builds a convergence table by solving at `num_steps`, `2 * num_steps`,
+/// `4 * num_steps`, ... and comparing against an exact solution at every
+/// mesh point.
+///
+/// The solver is produced by `build_solver`, so any method or option set can
+/// be studied. For a first-order method the ratio approaches 2.
+///
+/// # Arguments
+/// * `build_solver` - Builds a solved `EulerSolver1D` for a given number of steps
+/// * `exact_fn` - Exact solution y(t); a `t`-only expression can be wrapped as
+///   `|t| f(t, 0.0)` after `parse_expression`
+/// * `num_steps` - Number of steps at the coarsest level
+/// * `refinements` - Number of times the step size is halved
+///
+/// # Returns
+/// * `Result<Vec<ConvergenceRow>, SolverError>` - One row per level
+pub fn convergence_table(
+    build_solver: impl Fn(usize) -> Result<EulerSolver1D, SolverError>,
+    exact_fn: impl Fn(f64) -> f64,
+    num_steps: usize,
+    refinements: usize,
+) -> Result<Vec<ConvergenceRow>, SolverError> {
+    let mut rows: Vec<ConvergenceRow> = Vec::with_capacity(refinements + 1);
+
+    for level in 0..=refinements {
+        let solver = build_solver(num_steps << level)?;
+        let max_error = solver
+            .mesh
+            .iter()
+            .zip(solver.solution.iter())
+            .map(|(&t, &y)| (y - exact_fn(t)).abs())
+            .fold(0.0, f64::max);
+        let t_final = solver.mesh[solver.num_steps];
+        let final_error = (solver.solution[solver.num_steps] - exact_fn(t_final)).abs();
+
+        let ratio = rows.last().map_or(f64::NAN, |prev| prev.max_error / max_error);
+        rows.push(ConvergenceRow {
+            num_steps: solver.num_steps,
+            step_size: solver.step_size,
+            max_error,
+            final_error,
+            ratio,
+        });
+    }
+    Ok(rows)
+}
+
+// ================================
+// Section: Solution Verification
+// ================================
+
+/// Discrete residual used to check each step of a solution
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ResidualScheme {
+    /// `(y[k+1] - y[k]) / h - (f(t[k], y[k]) + f(t[k+1], y[k+1])) / 2`,
+    /// second-order accurate and neutral to the solver that produced the data
+    #[default]
+    Trapezoidal,
+    /// `(y[k+1] - y[k]) / h - f(t[k], y[k])`, exactly zero for forward Euler
+    /// output such as this crate's own exports
+    ForwardEuler,
+}
+
+/// Settings for `verify_solution_csv_with`
+#[derive(Debug, Clone)]
+pub struct VerifyOptions {
+    pub scheme: ResidualScheme, // Discrete residual to check each step with
+    pub delimiter: u8,          // CSV field delimiter, `,` by default
+    pub parameters: HashMap<String, f64>, // Named constants used by the expression
+}
+
+impl Default for VerifyOptions {
+    fn default() -> Self {
+        Self {
+            scheme: ResidualScheme::default(),
+            delimiter: b',',
+            parameters: HashMap::new(),
+        }
+    }
+}
+
+/// Discrete residual statistics of an externally computed solution
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResidualReport {
+    pub scheme: ResidualScheme, // Residual the steps were checked with
+    pub num_points: usize,      // Number of (t, y) rows checked
+    pub max_residual: f64,      // Largest |residual| over all steps
+    pub worst_t: f64,           // Start time of the step with the largest residual
+    pub tolerance: f64,         // Tolerance the residuals were checked against
+    pub failed_steps: Vec<usize>, // Step indices whose residual exceeds `tolerance`
+}
+
+impl ResidualReport {
+    /// Returns `true` if every step satisfied the tolerance
+    pub fn passed(&self) -> bool {
+        self.failed_steps.is_empty()
+    }
+}
+
+/// Checks a `(t, y)` CSV against dy/dt = f(t, y) without solving the ODE.
+///
+/// Each step is checked with the trapezoidal residual, which does not favour
+/// any particular solver. It is O(h^2) for exact solutions and for the
+/// output of higher-order methods. Use `verify_solution_csv_with` for the
+/// forward Euler residual, another delimiter or `[parameters]`. The first
+/// CSV row is treated as a header.
+///
+/// Verification is library-only; the command-line binary does not expose it.
+///
+/// # Arguments
+/// * `expression` - ODE right-hand side in terms of `t` and `y`
+/// * `csv_path` - CSV file with `t` in the first column and `y` in the second
+/// * `tol` - Largest admissible |residual| per step
+///
+/// # Returns
+/// * `Result<ResidualReport, SolverError>` - Residual statistics, or an error if the
+///   expression, the file or its time column is invalid
+pub fn verify_solution_csv(
+    expression: &str,
+    csv_path: &str,
+    tol: f64,
+) -> Result<ResidualReport, SolverError> {
+    verify_solution_csv_with(expression, csv_path, tol, &VerifyOptions::default())
+}
+
+/// Same as `verify_solution_csv`, with a selectable residual scheme, CSV
+/// delimiter and expression parameters (see `VerifyOptions`)
+///
+/// # Arguments
+/// * `expression`, `csv_path`, `tol` - As for `verify_solution_csv`
+/// * `options` - Residual scheme, delimiter and parameters
+///
+/// # Returns
+/// * `Result<ResidualReport, SolverError>` - Residual statistics or descriptive error
+pub fn verify_solution_csv_with(
+    expression: &str,
+    csv_path: &str,
+    tol: f64,
+    options: &VerifyOptions,
+) -> Result<ResidualReport, SolverError> {
+    let f = parse_expression(expression.to_string(), &options.parameters)
+        .map_err(|e| SolverError::InvalidExpression(e.to_string()))?;
+    let mut reader = csv::ReaderBuilder::new()
+        .delimiter(options.delimiter)
+        .from_path(csv_path)
+        .map_err(|e| SolverError::InvalidData(format!("`{}`: {}", csv_path, e)))?;
+    let scheme = options.scheme;
+
+    let (mut t, mut y) = (Vec::new(), Vec::new());
+    for (row, record) in reader.records().enumerate() {
+        let record = record.map_err(|e| SolverError::InvalidData(e.to_string()))?;
+        let field = |i: usize| -> Result<f64, SolverError> {
+            record.get(i).and_then(|v| v.trim().parse().ok()).ok_or_else(|| {
+                SolverError::InvalidData(format!("row {}: column {} is not a number", row + 1, i))
+            })
+        };
+        t.push(field(0)?);
+        y.push(field(1)?);
+    }
+    EulerSolver1D::validate_mesh(&t)?;
+
+    let mut report = ResidualReport {
+        scheme,
+        num_points: t.len(),
+        max_residual: 0.0,
+        worst_t: t[0],
+        tolerance: tol,
+        failed_steps: Vec::new(),
+    };
+    for k in 0..t.len() - 1 {
+        let slope = match scheme {
+            ResidualScheme::Trapezoidal => 0.5 * (f(t[k], y[k]) + f(t[k + 1], y[k + 1])),
+            ResidualScheme::ForwardEuler => f(t[k], y[k]),
+        };
+        let residual = ((y[k + 1] - y[k]) / (t[k + 1] - t[k]) - slope).abs();
+        let residual = if residual.is_nan() { f64::INFINITY } else { residual };  // NaN fails
+        if residual > tol {
+            report.failed_steps.push(k);
+        }
+        if residual > report.max_residual {
+            report.max_residual = residual;
+            report.worst_t = t[k];
+        }
+    }
+    Ok(report)
+}
 
 // ================================
 // Section: Unit Tests
@@ -178,7 +2500,7 @@ mod tests {
     #[test]
     fn test_expression_parser() {
         let expr_str = "cos(t) - y".to_string();
-        let f = parse_expression(expr_str).expect("Failed to parse expression");
+        let f = parse_expression(expr_str, &HashMap::new()).expect("Failed to parse expression");
         let val = f(0.0, 0.0);           // cos(0) - 0 = 1.0
         let expected = 1.0;
         assert!((val - expected).abs() < 1e-6); // Allow small floating-point error
@@ -193,15 +2515,1031 @@ mod tests {
         assert_eq!(mesh, expected);
     }
 
+    /// Tests whether the bound expression gives bit-identical results to
+    /// evaluating the same `Expr` with a freshly built `Context` per call,
+    /// and that unknown variables are rejected at parse time.
+    #[test]
+    fn test_expression_parser_matches_context_eval() {
+        let expr_str = "sin(t) * exp(-y) + t^2 / (1 + y^2)";
+        let f = parse_expression(expr_str.to_string(), &HashMap::new())
+            .expect("Failed to parse expression");
+        let expr = expr_str.parse::<Expr>().unwrap();
+
+        for i in 0..100 {
+            let (t, y) = (i as f64 * 0.37, 1.5 - i as f64 * 0.11);
+            let mut ctx = meval::Context::new();
+            ctx.var("t", t);
+            ctx.var("y", y);
+            let reference = expr.eval_with_context(ctx).unwrap();
+            assert_eq!(f(t, y).to_bits(), reference.to_bits());
+        }
+
+        let unbound = parse_expression("k * y".to_string(), &HashMap::new());
+        assert!(unbound.is_err());  // `k` is not bound
+    }
+
     /// Tests the Euler solver on a known ODE: dy/dt = y with y(0) = 1.
     /// The exact solution is y(t) = exp(t), so y(1) ≈ 2.71828.
     /// This test checks that the numerical solution is reasonably close.
     #[test]
     fn test_euler_solver_linear_case() {
         let f = |_t: f64, y: f64| y; // dy/dt = y
-        let solver = EulerSolver1D::new(f, 0.0, 1.0, 1.0, 10); // 10 steps over [0,1]
+        let solver = EulerSolver1D::new(f, 0.0, 1.0, 1.0, 10).unwrap(); // 10 steps over [0,1]
         let approx = solver.solution.last().unwrap();         // Get y(1)
         let exact = std::f64::consts::E;                      // ~2.71828
         assert!((approx - exact).abs() < 0.5); // Allow loose tolerance for Euler method
     }
+
+    /// Tests the convergence study on dy/dt = y with y(0) = 1 over [0, 1].
+    /// Forward Euler is first-order, so the estimated order should approach 1.0
+    /// and the error should shrink at every refinement.
+    #[test]
+    fn test_convergence_study_first_order() {
+        let f: Rc<dyn Fn(f64, f64) -> f64> = Rc::new(|_t, y| y);
+        let results = convergence_study(f, 0.0, 1.0, 1.0, 10, |t| t.exp(), 5).unwrap();
+
+        assert_eq!(results.len(), 6);
+        assert_eq!(results[5].0, 320);                       // 10 * 2^5 steps
+        assert!(results[0].2.is_nan());                      // No previous level
+        for pair in results.windows(2) {
+            assert!(pair[1].1 < pair[0].1);                  // Error decreases
+        }
+        let final_order = results.last().unwrap().2;
+        assert!((final_order - 1.0).abs() < 0.05);           // First-order accuracy
+    }
+
+    /// Tests whether an RPN expression equivalent to "cos(t) - y" evaluates
+    /// to the same values as the `meval` parser, and that malformed token
+    /// lists are rejected up front.
+    #[test]
+    fn test_rpn_parser_matches_meval() {
+        let rpn = parse_rpn(&["t", "cos", "y", "-"], &HashMap::new()).expect("Failed to parse RPN");
+        let infix = parse_expression("cos(t) - y".to_string(), &HashMap::new())
+            .expect("Failed to parse expression");
+        for &(t, y) in &[(0.0, 0.0), (0.5, 1.0), (2.0, -3.5)] {
+            assert_eq!(rpn(t, y), infix(t, y));
+        }
+
+        let none = HashMap::new();
+        assert!(parse_rpn(&["t", "+"], &none).is_err());  // Missing operand
+        assert!(parse_rpn(&["t", "y"], &none).is_err());  // Leftover value
+        assert!(parse_rpn(&["t", "foo"], &none).is_err()); // Unknown token
+    }
+
+    /// Tests whether the control value switches exactly at the scheduled
+    /// times, and that the solver integrates dy/dt = u with u = 1 on [0, 2)
+    /// and u = 0 afterwards to y(4) = 2.
+    #[test]
+    fn test_control_schedule_zero_order_hold() {
+        let schedule = ControlSchedule::new(vec![(0.0, 1.0), (2.0, 0.0)]).unwrap();
+        assert_eq!(schedule.value_at(-1.0), 1.0);         // Before first breakpoint
+        assert_eq!(schedule.value_at(1.999), 1.0);
+        assert_eq!(schedule.value_at(2.0), 0.0);          // Switches at t = 2
+        assert_eq!(schedule.value_at(10.0), 0.0);
+
+        let f = parse_controlled_expression("u".to_string(), schedule, &HashMap::new()).unwrap();
+        let solver = EulerSolver1D::new(f, 0.0, 4.0, 0.0, 8).unwrap();  // h = 0.5
+        assert_eq!(*solver.solution.last().unwrap(), 2.0);
+
+        assert!(ControlSchedule::new(vec![(1.0, 0.0), (1.0, 1.0)]).is_err());
+    }
+
+    /// Tests the solver on an explicit non-uniform mesh for dy/dt = 1,
+    /// where each local step must be used exactly so y(t) = t at every point,
+    /// and checks that unsorted or too-short meshes are rejected, including
+    /// the uniform meshes `new` builds from zero steps or a reversed domain.
+    #[test]
+    fn test_with_mesh_non_uniform() {
+        let mesh = vec![0.0, 0.01, 0.05, 0.25, 1.0, 3.0];
+        let solver = EulerSolver1D::with_mesh(|_t, _y| 1.0, mesh.clone(), 0.0).unwrap();
+        assert_eq!(solver.num_steps, 5);
+        assert_eq!(solver.t_end, 3.0);
+        for (&t, &y) in solver.mesh.iter().zip(solver.solution.iter()) {
+            assert!((y - t).abs() < 1e-12);
+        }
+
+        let unsorted = EulerSolver1D::with_mesh(|_t, y| y, vec![0.0, 2.0, 1.0], 1.0);
+        assert!(matches!(unsorted, Err(SolverError::InvalidMesh(_))));
+        let too_short = EulerSolver1D::with_mesh(|_t, y| y, vec![0.0], 1.0);
+        assert!(matches!(too_short, Err(SolverError::InvalidMesh(_))));
+        let no_steps = EulerSolver1D::new(|_t, y| y, 0.0, 1.0, 1.0, 0);
+        assert!(matches!(no_steps, Err(SolverError::InvalidMesh(_))));
+        let reversed = EulerSolver1D::new(|_t, y| y, 1.0, 0.0, 1.0, 10);
+        assert!(matches!(reversed, Err(SolverError::InvalidMesh(_))));
+    }
+
+    /// Tests whether the same configuration loads identically from INI,
+    /// TOML and JSON files, and that unknown extensions are rejected.
+    #[test]
+    fn test_config_from_path_formats() {
+        let dir = std::env::temp_dir();
+        let id = std::process::id();
+        let ini = dir.join(format!("euler_cfg_{}.ini", id));
+        let toml = dir.join(format!("euler_cfg_{}.toml", id));
+        let json = dir.join(format!("euler_cfg_{}.json", id));
+
+        std::fs::write(&ini, "[mesh_1_d]\nn = 20\ndomain_start = 0.0\ndomain_end = 2.0\n\
+            [initial_conditions]\ny_0 = 1.5\n[ode_function]\nexpression = -y\n\
+            [output]\ncsv_file = out.csv\n").unwrap();
+        std::fs::write(&toml, "[mesh_1_d]\nn = 20\ndomain_start = 0.0\ndomain_end = 2.0\n\
+            [initial_conditions]\ny_0 = 1.5\n[ode_function]\nexpression = \"-y\"\n\
+            [output]\ncsv_file = \"out.csv\"\n").unwrap();
+        std::fs::write(&json, r#"{"mesh_1_d": {"n": 20, "domain_start": 0.0, "domain_end": 2.0},
+            "initial_conditions": {"y_0": 1.5}, "ode_function": {"expression": "-y"},
+            "output": {"csv_file": "out.csv"}}"#).unwrap();
+
+        for path in [&ini, &toml, &json] {
+            let config = SolverConfig::from_path(path).expect("Failed to load config");
+            assert_eq!(config.mesh_1_d.n, 20);
+            assert_eq!(config.mesh_1_d.domain_end, 2.0);
+            assert_eq!(config.initial_conditions.y_0, 1.5);
+            assert_eq!(config.ode_function.expression, "-y");
+            assert_eq!(config.output.csv_file, "out.csv");
+            std::fs::remove_file(path).unwrap();
+        }
+
+        assert!(SolverConfig::from_path(Path::new("config.yaml")).is_err());
+    }
+
+    /// Tests whether `format = json` in the output config selects the JSON
+    /// backend, and that the exported document carries the metadata and
+    /// stores values as real JSON numbers rather than strings.
+    #[test]
+    fn test_export_json_via_config() {
+        let path = std::env::temp_dir().join(format!("euler_out_{}.json", std::process::id()));
+        let ini = format!("[output]\ncsv_file = {}\nformat = json\n", path.display());
+        let output: OutputConfig = Config::builder()
+            .add_source(File::from_str(&ini, FileFormat::Ini))
+            .build()
+            .unwrap()
+            .get("output")
+            .unwrap();
+        assert_eq!(output.format, OutputFormat::Json);
+
+        let mut solver = EulerSolver1D::new(|_t, y| y, 0.0, 1.0, 1.0, 4).unwrap();
+        solver.expression = Some("y".to_string());
+        solver.export(&output).expect("Failed to export JSON");
+
+        let text = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        let doc: serde_json::Value = serde_json::from_str(&text).unwrap();
+        assert_eq!(doc["num_steps"], 4);
+        assert_eq!(doc["t_end"], 1.0);
+        assert_eq!(doc["expression"], "y");
+        let points = doc["solution"].as_array().unwrap();
+        assert_eq!(points.len(), 5);
+        assert_eq!(points[4]["t"].as_f64(), Some(1.0));
+        assert_eq!(points[4]["y"].as_f64(), Some(solver.solution[4]));
+    }
+
+    /// Tests whether the same base seed reproduces an identical SDE ensemble,
+    /// an adjacent seed shares none of its paths, and that for pure Brownian motion
+    /// (dy = dW) the ensemble variance at t = 1 is close to 1.
+    #[test]
+    fn test_sde_ensemble_reproducible() {
+        let mesh = EulerSolver1D::generate_mesh(0.0, 1.0, 50);
+        let drift = |_t: f64, y: f64| -0.5 * y;
+        let diffusion = |_t: f64, _y: f64| 0.3;
+
+        let a = solve_sde_ensemble(drift, diffusion, 1.0, 20, 42, &mesh).unwrap();
+        let b = solve_sde_ensemble(drift, diffusion, 1.0, 20, 42, &mesh).unwrap();
+        let c = solve_sde_ensemble(drift, diffusion, 1.0, 20, 43, &mesh).unwrap();
+        assert_eq!(a.paths, b.paths);
+        assert!(c.paths.iter().all(|path| !a.paths.contains(path)));  // No shifted reuse
+
+        let brownian = solve_sde_ensemble(|_t, _y| 0.0, |_t, _y| 1.0, 0.0, 4000, 7, &mesh).unwrap();
+        let (mean, variance) = brownian.mean_and_variance();
+        assert_eq!(variance[0], 0.0);
+        assert!(mean[50].abs() < 0.1);
+        assert!((variance[50] - 1.0).abs() < 0.1);
+    }
+
+    /// Tests event detection on dy/dt = -1 with y(0) = 1 and event g = y.
+    /// With h = 2/3 the solution jumps from 1/3 to -1/3, so the interpolated
+    /// crossing is at t = 1.0 and the mesh/solution stop there. An event that
+    /// never triggers must leave the full solve untouched.
+    #[test]
+    fn test_event_stops_integration() {
+        let mesh = EulerSolver1D::generate_mesh(0.0, 2.0, 3);
+        let options = SolverOptions {
+            event: Some(Box::new(|_t, y| y)),
+            ..SolverOptions::default()
+        };
+        let solver =
+            EulerSolver1D::with_options(|_t, _y| -1.0, mesh.clone(), 1.0, options).unwrap();
+        let t_event = solver.event_time.expect("Event should trigger");
+        assert!((t_event - 1.0).abs() < 1e-12);
+        assert_eq!(solver.mesh.len(), 3);
+        assert_eq!(solver.solution.len(), 3);
+        assert_eq!(solver.num_steps, 2);
+        assert_eq!(*solver.mesh.last().unwrap(), t_event);
+        assert!(solver.solution.last().unwrap().abs() < 1e-12);
+
+        let options = SolverOptions {
+            event: Some(Box::new(|_t, y| y + 10.0)),
+            ..SolverOptions::default()
+        };
+        let solver = EulerSolver1D::with_options(|_t, _y| -1.0, mesh, 1.0, options).unwrap();
+        assert_eq!(solver.event_time, None);
+        assert_eq!(solver.solution.len(), 4);
+        assert_eq!(solver.t_end, 2.0);
+    }
+
+    /// Tests the interval exit time on the growing solution y = 2t.
+    /// With h = 1 the solution jumps from 2 to 4, so leaving [-1, 3] through
+    /// the upper bound is interpolated to t = 1.5; a wide interval never exits.
+    #[test]
+    fn test_first_exit_time_interpolated() {
+        let solver = EulerSolver1D::new(|_t, _y| 2.0, 0.0, 4.0, 0.0, 4).unwrap();
+        let t_exit = solver.first_exit_time(-1.0, 3.0).expect("Solution should exit");
+        assert!((t_exit - 1.5).abs() < 1e-12);
+
+        assert_eq!(solver.first_exit_time(-1.0, 100.0), None);
+        assert_eq!(solver.first_exit_time(1.0, 3.0), Some(0.0));  // Starts outside
+    }
+
+    /// Tests whether `format = tsv` selects the tab-separated backend and
+    /// that the file has the same rows as the CSV export with tab delimiters.
+    #[test]
+    fn test_export_tsv_via_config() {
+        let dir = std::env::temp_dir();
+        let tsv_path = dir.join(format!("euler_out_{}.tsv", std::process::id()));
+        let csv_path = dir.join(format!("euler_out_{}.csv", std::process::id()));
+        let ini = format!("[output]\ncsv_file = {}\nformat = tsv\n", tsv_path.display());
+        let output: OutputConfig = Config::builder()
+            .add_source(File::from_str(&ini, FileFormat::Ini))
+            .build()
+            .unwrap()
+            .get("output")
+            .unwrap();
+        assert_eq!(output.format, OutputFormat::Tsv);
+
+        let solver = EulerSolver1D::new(|t, y| t - y, 0.0, 1.0, 1.0, 4).unwrap();
+        solver.export(&output).expect("Failed to export TSV");
+        solver.export_to_csv(csv_path.to_str().unwrap()).unwrap();
+
+        let tsv = std::fs::read_to_string(&tsv_path).unwrap();
+        let csv = std::fs::read_to_string(&csv_path).unwrap();
+        std::fs::remove_file(&tsv_path).unwrap();
+        std::fs::remove_file(&csv_path).unwrap();
+        assert!(tsv.starts_with("t\ty(t)\n"));
+        assert_eq!(tsv.lines().count(), 6);
+        assert_eq!(tsv.replace('\t', ","), csv);
+    }
+
+    /// Tests whether exported CSV values parse back to exactly the computed
+    /// `f64`s, both with the default formatting and with a fixed precision,
+    /// and that a semicolon delimiter from the output config is honoured.
+    #[test]
+    fn test_csv_export_round_trips_exactly() {
+        let path = std::env::temp_dir().join(format!("euler_prec_{}.csv", std::process::id()));
+        let solver = EulerSolver1D::new(|t, y| t.sin() - y / 3.0, 0.0, 1.0, 0.1, 7).unwrap();
+
+        for precision in [None, Some(16)] {
+            let output = OutputConfig {
+                csv_file: path.to_str().unwrap().to_string(),
+                format: OutputFormat::Csv,
+                precision,
+                delimiter: Some(";".to_string()),
+                include_cumulative: false,
+            };
+            solver.export(&output).expect("Failed to export CSV");
+
+            let text = std::fs::read_to_string(&path).unwrap();
+            let rows: Vec<&str> = text.lines().skip(1).collect();
+            assert_eq!(rows.len(), solver.solution.len());
+            for (row, (&t, &y)) in rows.iter().zip(solver.mesh.iter().zip(solver.solution.iter())) {
+                let (t_str, y_str) = row.split_once(';').expect("Expected `;` delimiter");
+                assert_eq!(t_str.parse::<f64>().unwrap().to_bits(), t.to_bits());
+                assert_eq!(y_str.parse::<f64>().unwrap().to_bits(), y.to_bits());
+            }
+        }
+        std::fs::remove_file(&path).unwrap();
+
+        let bad = OutputConfig {
+            csv_file: path.to_str().unwrap().to_string(),
+            format: OutputFormat::Csv,
+            precision: None,
+            delimiter: Some("::".to_string()),
+            include_cumulative: false,
+        };
+        assert!(solver.export(&bad).is_err());
+    }
+
+    /// Tests backward Euler on the stiff problem dy/dt = -1000*y with h = 0.1.
+    /// Forward Euler multiplies y by -99 each step and blows up, while the
+    /// implicit method stays bounded and decays monotonically toward zero.
+    #[test]
+    fn test_backward_euler_stiff_decay() {
+        let mesh = EulerSolver1D::generate_mesh(0.0, 1.0, 10);
+        let explicit = EulerSolver1D::with_mesh(|_t, y| -1000.0 * y, mesh.clone(), 1.0).unwrap();
+        assert!(explicit.solution.last().unwrap().abs() > 1e10);
+
+        let options = SolverOptions {
+            method: SolverMethod::BackwardEuler,
+            ..SolverOptions::default()
+        };
+        let implicit =
+            EulerSolver1D::with_options(|_t, y| -1000.0 * y, mesh, 1.0, options).unwrap();
+        for pair in implicit.solution.windows(2) {
+            assert!(pair[1] > 0.0 && pair[1] < pair[0]);
+        }
+        let expected = 101f64.powi(-10);  // y[k] = (1 + 100)^(-k)
+        assert!(((implicit.solution[10] - expected) / expected).abs() < 1e-6);
+    }
+
+    /// Tests a sweep over y0 = 0, 1, 2 for dy/dt = -y. The problem is linear,
+    /// so each trajectory is exactly y0 times the y0 = 1 run, the CSV export
+    /// has one column per initial value, and `[output]` precision and
+    /// delimiter settings apply to sweeps as well.
+    #[test]
+    fn test_solve_sweep_initial_conditions() {
+        let solver = EulerSolver1D::new(|_t, y| -y, 0.0, 2.0, 1.0, 8).unwrap();
+        let sweep = solver.solve_sweep(&[0.0, 1.0, 2.0]).unwrap();
+
+        assert_eq!(sweep.trajectories.len(), 3);
+        assert_eq!(sweep.trajectories[1], solver.solution);
+        for k in 0..sweep.mesh.len() {
+            assert_eq!(sweep.trajectories[0][k], 0.0);
+            assert_eq!(sweep.trajectories[2][k], 2.0 * sweep.trajectories[1][k]);
+        }
+
+        let path = std::env::temp_dir().join(format!("euler_sweep_{}.csv", std::process::id()));
+        sweep.export_to_csv(path.to_str().unwrap()).unwrap();
+        let text = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert!(text.starts_with("t,y0=0,y0=1,y0=2\n"));
+        assert_eq!(text.lines().count(), 10);
+
+        let output = OutputConfig {
+            csv_file: path.to_str().unwrap().to_string(),
+            format: OutputFormat::Csv,
+            precision: Some(2),
+            delimiter: Some(";".to_string()),
+            include_cumulative: false,
+        };
+        sweep.export_in(&output, None).unwrap();
+        let text = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(text.lines().nth(1), Some("0.00e0;0.00e0;1.00e0;2.00e0"));
+        let json = OutputConfig { format: OutputFormat::Json, ..output };
+        assert!(sweep.export_in(&json, None).is_err());
+    }
+
+    /// Tests whether a binary export reads back with the same header fields
+    /// and bit-identical values, and that truncated files are rejected.
+    #[test]
+    fn test_binary_round_trip() {
+        let path = std::env::temp_dir().join(format!("euler_bin_{}.bin", std::process::id()));
+        let path = path.to_str().unwrap();
+        let solver = EulerSolver1D::new(|t, y| t.cos() - y, 0.5, 3.0, 1.0, 25).unwrap();
+        solver.export_binary(path).unwrap();
+
+        let bytes = std::fs::read(path).unwrap();
+        assert_eq!(bytes.len(), BINARY_HEADER_LEN + 2 * 8 * 26);
+
+        let loaded = BinarySolution::from_binary(path).unwrap();
+        assert_eq!(loaded.t_start, solver.t_start);
+        assert_eq!(loaded.t_end, solver.t_end);
+        assert_eq!(loaded.mesh, solver.mesh);
+        assert_eq!(loaded.solution, solver.solution);
+
+        // Non-uniform meshes come back exactly
+        let uneven = EulerSolver1D::with_mesh(|_t, y| -y, vec![0.0, 0.1, 0.5, 2.0], 1.0).unwrap();
+        uneven.export_binary(path).unwrap();
+        assert_eq!(BinarySolution::from_binary(path).unwrap().mesh, uneven.mesh);
+
+        std::fs::write(path, &bytes[..bytes.len() - 4]).unwrap();
+        assert!(BinarySolution::from_binary(path).is_err());
+        std::fs::remove_file(path).unwrap();
+    }
+
+    /// Tests a mesh whose third point sits 1e-15 below the second, as float
+    /// rounding can produce. The strict check rejects it, while a relative
+    /// tolerance of 1e-12 merges the near-duplicate and solves on 3 points.
+    #[test]
+    fn test_mesh_tolerance_merges_near_duplicates() {
+        let mesh = vec![0.0, 0.5, 0.5 - 1e-15, 1.0];
+        let strict =
+            EulerSolver1D::with_options(|_t, _y| 1.0, mesh.clone(), 0.0, SolverOptions::default());
+        assert!(matches!(strict, Err(SolverError::InvalidMesh(_))));
+
+        let options = SolverOptions {
+            mesh_tolerance: 1e-12,
+            ..SolverOptions::default()
+        };
+        let solver = EulerSolver1D::with_options(|_t, _y| 1.0, mesh, 0.0, options).unwrap();
+        assert_eq!(solver.mesh, vec![0.0, 0.5, 1.0]);
+        assert_eq!(solver.solution, vec![0.0, 0.5, 1.0]);
+    }
+
+    /// Tests whether parameters declared in a `[parameters]` config section
+    /// are bound into the expression, that a parameter named like an ODE
+    /// variable or a builtin is rejected, and that the RPN and controlled
+    /// parsers accept the same parameters.
+    #[test]
+    fn test_expression_parameters_from_config() {
+        let ini = "[mesh_1_d]\nn = 10\ndomain_start = 0.0\ndomain_end = 1.0\n\
+            [initial_conditions]\ny_0 = 1.0\n[ode_function]\nexpression = -k*y + g\n\
+            [output]\ncsv_file = out.csv\n[parameters]\nk = 0.5\ng = 9.81\n";
+        let config: SolverConfig = Config::builder()
+            .add_source(File::from_str(ini, FileFormat::Ini))
+            .build()
+            .unwrap()
+            .try_deserialize()
+            .unwrap();
+        assert_eq!(config.parameters.len(), 2);
+
+        let f = parse_expression(config.ode_function.expression, &config.parameters).unwrap();
+        assert_eq!(f(0.0, 2.0), -0.5 * 2.0 + 9.81);
+
+        let clash = HashMap::from([("y".to_string(), 1.0)]);
+        let err = parse_expression("y".to_string(), &clash).err().unwrap();
+        assert!(err.to_string().contains("collides"));
+        let builtin = HashMap::from([("pi".to_string(), 3.0)]);
+        let err = parse_expression("pi * y".to_string(), &builtin).err().unwrap();
+        assert!(err.to_string().contains("builtin"));
+
+        // The RPN and controlled parsers take the same parameters
+        let k = HashMap::from([("k".to_string(), 0.5)]);
+        let rpn = parse_rpn(&["k", "neg", "y", "*"], &k).unwrap();
+        assert_eq!(rpn(0.0, 2.0), -1.0);
+        assert!(parse_rpn(&["pi"], &builtin).is_err());
+        let schedule = ControlSchedule::new(vec![(0.0, 4.0)]).unwrap();
+        let controlled = parse_controlled_expression("k * u - y".to_string(), schedule, &k);
+        assert_eq!(controlled.unwrap()(0.0, 1.0), 1.0);
+        let schedule = ControlSchedule::new(vec![(0.0, 4.0)]).unwrap();
+        let u_clash = HashMap::from([("u".to_string(), 1.0)]);
+        assert!(parse_controlled_expression("u".to_string(), schedule, &u_clash).is_err());
+    }
+
+    /// Tests nullclines of the linear system x' = y - x, y' = x + y - 1,
+    /// whose nullclines are the lines y = x and y = 1 - x. Linear
+    /// interpolation along grid edges is exact here, so every reported point
+    /// must lie on the corresponding line.
+    #[test]
+    fn test_nullclines_linear_system() {
+        let system = |_t: f64, s: &[f64]| vec![s[1] - s[0], s[0] + s[1] - 1.0];
+        let solver = EulerSolverND::new(system, 0.0, 1.0, vec![0.0, 0.0], 10);
+        let (f0_zero, f1_zero) = solver.nullclines((-1.0, 2.0), (-1.0, 2.0), 30);
+
+        assert!(f0_zero.len() >= 31);
+        assert!(f1_zero.len() >= 31);
+        for &(x, y) in &f0_zero {
+            assert!((y - x).abs() < 1e-9);
+        }
+        for &(x, y) in &f1_zero {
+            assert!((x + y - 1.0).abs() < 1e-9);
+        }
+    }
+
+    /// Tests divergence detection on the unstable iteration dy/dt = -1000*y
+    /// with h = 0.1, where y[k] = (-99)^k. A bound of 1e12 is first exceeded
+    /// at mesh index 7 (|y| = 99^7 ~ 9.3e13), and without a bound the
+    /// overflow to infinity is still caught instead of being returned.
+    #[test]
+    fn test_divergence_detected() {
+        let mesh = EulerSolver1D::generate_mesh(0.0, 1.0, 10);
+        let options = SolverOptions {
+            divergence_bound: Some(1e12),
+            ..SolverOptions::default()
+        };
+        let result = EulerSolver1D::with_options(|_t, y| -1000.0 * y, mesh, 1.0, options);
+        match result {
+            Err(SolverError::Diverged { step, t, value }) => {
+                assert_eq!(step, 7);
+                assert!((t - 0.7).abs() < 1e-12);
+                assert!(value.abs() > 1e12);
+            }
+            other => panic!("Expected divergence, got {:?}", other.map(|s| s.solution)),
+        }
+
+        let overflow = EulerSolver1D::new(|_t, y| y * y, 0.0, 10.0, 10.0, 100);
+        assert!(matches!(overflow, Err(SolverError::Diverged { .. })));
+    }
+
+    /// Tests an expression that blows up mid-integration: 1/(t - 1) is
+    /// infinite when evaluated at t = 1 (mesh index 5 with h = 0.2), so the
+    /// first bad value is y[6]. The error must identify that step, its time
+    /// and the infinite value instead of returning a vector full of inf/NaN.
+    #[test]
+    fn test_blow_up_reports_step_and_value() {
+        let f = parse_expression("1 / (t - 1)".to_string(), &HashMap::new()).unwrap();
+        match EulerSolver1D::new(f, 0.0, 2.0, 0.0, 10) {
+            Err(SolverError::Diverged { step, t, value }) => {
+                assert_eq!(step, 6);
+                assert!((t - 1.2).abs() < 1e-12);
+                assert!(value.is_infinite());
+            }
+            other => panic!("Expected divergence, got {:?}", other.map(|s| s.solution)),
+        }
+    }
+
+    /// Tests the convergence table on dy/dt = -y, y(0) = 1 over [0, 2]
+    /// for both Euler methods. Both are first-order, so halving the step
+    /// should roughly halve the max error and the ratio should approach 2.
+    #[test]
+    fn test_convergence_table_ratio_approaches_two() {
+        for method in [SolverMethod::ForwardEuler, SolverMethod::BackwardEuler] {
+            let build = |n: usize| {
+                let options = SolverOptions {
+                    method,
+                    ..SolverOptions::default()
+                };
+                let mesh = EulerSolver1D::generate_mesh(0.0, 2.0, n);
+                EulerSolver1D::with_options(|_t, y| -y, mesh, 1.0, options)
+            };
+            let rows = convergence_table(build, |t| (-t).exp(), 20, 4).unwrap();
+
+            assert_eq!(rows.len(), 5);
+            assert_eq!(rows[4].num_steps, 320);
+            assert!((rows[4].step_size - 2.0 / 320.0).abs() < 1e-15);
+            assert!(rows[0].ratio.is_nan());
+            assert!((rows[4].ratio - 2.0).abs() < 0.05);
+        }
+    }
+
+    /// Tests whether NDJSON output has one line per mesh point and each
+    /// line parses as a JSON object with the `t` and `y` values.
+    #[test]
+    fn test_export_ndjson_lines() {
+        let solver = EulerSolver1D::new(|t, y| t - y, 0.0, 1.0, 0.5, 5).unwrap();
+        let mut buffer: Vec<u8> = Vec::new();
+        solver.export_ndjson(&mut buffer).unwrap();
+
+        let text = String::from_utf8(buffer).unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines.len(), 6);
+        for (line, (&t, &y)) in lines.iter().zip(solver.mesh.iter().zip(solver.solution.iter())) {
+            let record: serde_json::Value = serde_json::from_str(line).unwrap();
+            assert_eq!(record.as_object().unwrap().len(), 2);
+            assert!((record["t"].as_f64().unwrap() - t).abs() <= 1e-15 * t.abs());
+            assert!((record["y"].as_f64().unwrap() - y).abs() <= 1e-15 * y.abs());
+        }
+    }
+
+    /// Tests whether the builder produces the same solution as the positional
+    /// constructor, and reports missing fields and bad expressions as errors.
+    #[test]
+    fn test_builder_validates_and_builds() {
+        let built = EulerSolver1D::builder()
+            .expression("cos(t) - y")
+            .domain(0.0, 5.0)
+            .steps(10)
+            .initial(1.0)
+            .build()
+            .unwrap();
+        let direct = EulerSolver1D::new(|t: f64, y| t.cos() - y, 0.0, 5.0, 1.0, 10).unwrap();
+        assert_eq!(built.solution, direct.solution);
+        assert_eq!(built.expression.as_deref(), Some("cos(t) - y"));
+
+        let missing = EulerSolver1D::builder().expression("y").domain(0.0, 1.0).steps(4).build();
+        assert!(matches!(missing, Err(SolverError::MissingField("initial"))));
+
+        let bad = EulerSolver1D::builder()
+            .expression("cos(t")
+            .domain(0.0, 1.0)
+            .steps(4)
+            .initial(1.0)
+            .build();
+        assert!(matches!(bad, Err(SolverError::InvalidExpression(_))));
+    }
+
+    /// Tests the arc length of the straight line y = 0.75 t over [0, 4],
+    /// which is the 3-4-5 triangle hypotenuse of length 5, on a non-uniform mesh.
+    #[test]
+    fn test_arc_length_straight_line() {
+        let mesh = vec![0.0, 0.1, 0.5, 1.7, 3.0, 4.0];
+        let solver = EulerSolver1D::with_mesh(|_t, _y| 0.75, mesh, 0.0).unwrap();
+        assert!((solver.arc_length() - 5.0).abs() < 1e-12);
+    }
+
+    /// Tests whether the progress callback fires every `stride` steps with
+    /// the same (step, t, y) as the final solution, and that observing the
+    /// solve does not change the result.
+    #[test]
+    fn test_progress_callback_stride() {
+        let calls = Rc::new(std::cell::RefCell::new(Vec::new()));
+        let sink = Rc::clone(&calls);
+        let options = SolverOptions {
+            progress: Some(ProgressCallback::new(10, move |k, t, y| {
+                sink.borrow_mut().push((k, t, y))
+            })),
+            ..SolverOptions::default()
+        };
+        let mesh = EulerSolver1D::generate_mesh(0.0, 2.0, 95);
+        let observed =
+            EulerSolver1D::with_options(|t, y| t - y, mesh.clone(), 1.0, options).unwrap();
+        let plain = EulerSolver1D::with_mesh(|t, y| t - y, mesh, 1.0).unwrap();
+        assert_eq!(observed.solution, plain.solution);
+
+        let calls = calls.borrow();
+        assert_eq!(calls.len(), 9);                     // Steps 10, 20, ..., 90
+        for (i, &(k, t, y)) in calls.iter().enumerate() {
+            assert_eq!(k, 10 * (i + 1));
+            assert_eq!(t, plain.mesh[k]);
+            assert_eq!(y, plain.solution[k]);
+        }
+    }
+
+    /// Tests whether `stats` counts one evaluation per forward Euler step,
+    /// more for backward Euler, and reports the step extremes of the mesh.
+    #[test]
+    fn test_solver_stats() {
+        let mesh = vec![0.0, 0.1, 0.3, 0.6, 1.0];
+        let forward = EulerSolver1D::with_mesh(|_t, y| -y, mesh.clone(), 1.0).unwrap();
+        assert_eq!(forward.stats.evaluations, 4);
+        assert!((forward.stats.min_step - 0.1).abs() < 1e-12);
+        assert!((forward.stats.max_step - 0.4).abs() < 1e-12);
+
+        let options = SolverOptions {
+            method: SolverMethod::BackwardEuler,
+            ..SolverOptions::default()
+        };
+        let backward = EulerSolver1D::with_options(|_t, y| -y, mesh, 1.0, options).unwrap();
+        assert!(backward.stats.evaluations > 4 * 3);    // Predictor + residual + FD pair
+        assert!(backward.stats.to_string().ends_with(&format!(
+            "{} evals",
+            backward.stats.evaluations
+        )));
+    }
+
+    /// Tests whether a CSV exported by the solver passes the forward Euler
+    /// check and a copy with one corrupted value fails at the affected steps.
+    #[test]
+    fn test_verify_solution_csv() {
+        let dir = std::env::temp_dir();
+        let good = dir.join(format!("euler_verify_good_{}.csv", std::process::id()));
+        let bad = dir.join(format!("euler_verify_bad_{}.csv", std::process::id()));
+        let (good, bad) = (good.to_str().unwrap(), bad.to_str().unwrap());
+
+        let solver = EulerSolver1D::new(|t, y| t - y, 0.0, 1.0, 1.0, 10).unwrap();
+        solver.export_to_csv(good).unwrap();
+        let euler = VerifyOptions { scheme: ResidualScheme::ForwardEuler, ..Default::default() };
+        let report = verify_solution_csv_with("t - y", good, 1e-9, &euler).unwrap();
+        assert!(report.passed());
+        assert_eq!(report.num_points, 11);
+
+        let mut corrupted = solver.solution.clone();
+        corrupted[5] += 1e-3;
+        let mut writer = csv::Writer::from_path(bad).unwrap();
+        writer.write_record(["t", "y(t)"]).unwrap();
+        for (t, y) in solver.mesh.iter().zip(&corrupted) {
+            writer.write_record(&[t.to_string(), y.to_string()]).unwrap();
+        }
+        writer.flush().unwrap();
+        let report = verify_solution_csv_with("t - y", bad, 1e-9, &euler).unwrap();
+        assert!(!report.passed());
+        assert_eq!(report.failed_steps, vec![4, 5]);    // Steps into and out of y[5]
+
+        std::fs::remove_file(good).ok();
+        std::fs::remove_file(bad).ok();
+    }
+
+    /// Tests whether the Poincaré section of a periodic solution returns the
+    /// same value in every period, at the expected number of sample times.
+    #[test]
+    fn test_poincare_section() {
+        use std::f64::consts::PI;
+        // y' = cos(t) with 100 steps per period: the Euler sum is periodic too
+        let solver = EulerSolver1D::new(|t, _y| t.cos(), 0.0, 6.0 * PI, 0.0, 300).unwrap();
+        let section = solver.poincare_section(2.0 * PI, 0.5);
+        assert_eq!(section.len(), 3);                   // t = 0.5, 0.5 + 2pi, 0.5 + 4pi
+        assert!((section[0] - 0.5f64.sin()).abs() < 0.05);
+        for y in &section[1..] {
+            assert!((y - section[0]).abs() < 1e-9);
+        }
+        assert!(solver.poincare_section(0.0, 0.5).is_empty());
+    }
+
+    /// Tests whether `min_output_interval` suppresses calls that come sooner
+    /// than the interval after the previous one, using manual clock readings.
+    #[test]
+    fn test_progress_min_output_interval() {
+        let mut progress = ProgressCallback::new(1, |_, _, _| {})
+            .with_min_output_interval(Duration::from_millis(100));
+        let start = Instant::now();
+        let at = |ms: u64| move || start + Duration::from_millis(ms);
+        let emitted: Vec<bool> =
+            [0, 40, 99, 100, 150, 250].iter().map(|&ms| progress.should_emit(at(ms))).collect();
+        assert_eq!(emitted, vec![true, false, false, true, false, true]);
+
+        // Zero interval never throttles, and a real solve still calls back
+        let mut unthrottled = ProgressCallback::new(1, |_, _, _| {});
+        assert!((0..5).all(|_| unthrottled.should_emit(|| panic!("clock read"))));
+        let calls = Rc::new(Cell::new(0));
+        let sink = Rc::clone(&calls);
+        let throttled = ProgressCallback::new(1, move |_, _, _| sink.set(sink.get() + 1))
+            .with_min_output_interval(Duration::from_secs(3600));
+        let options = SolverOptions { progress: Some(throttled), ..SolverOptions::default() };
+        let mesh = EulerSolver1D::generate_mesh(0.0, 1.0, 50);
+        EulerSolver1D::with_options(|_t, y| -y, mesh, 1.0, options).unwrap();
+        assert_eq!(calls.get(), 1);                     // Only the first point
+    }
+
+    /// Tests whether the Lyapunov estimate is about -1 for a stable diagonal
+    /// linear system (its slowest rate) and about zero for a rotation.
+    #[test]
+    fn test_max_lyapunov_linear_systems() {
+        let stable = EulerSolverND::new(
+            |_t, y| vec![-y[0], -2.0 * y[1]],
+            0.0,
+            20.0,
+            vec![1.0, 1.0],
+            2000,
+        );
+        let lambda = stable.max_lyapunov();
+        assert!(lambda < 0.0);
+        assert!((lambda + 1.0).abs() < 0.05, "lambda = {}", lambda);
+
+        let rotation =
+            EulerSolverND::new(|_t, y| vec![y[1], -y[0]], 0.0, 20.0, vec![1.0, 0.0], 2000);
+        assert!(rotation.max_lyapunov().abs() < 0.01);
+    }
+
+    /// Tests whether one seed reproduces every stochastic feature across runs:
+    /// single paths, ensembles and the shared generator, with the ensemble
+    /// streams distinct from the standalone path.
+    #[test]
+    fn test_seed_reproducibility_across_features() {
+        let mesh = EulerSolver1D::generate_mesh(0.0, 1.0, 50);
+        let run = |seed: u64| {
+            let path = solve_sde(|_t, y| -y, |_t, _y| 0.3, &mesh, 1.0, seed).unwrap();
+            let ensemble = solve_sde_ensemble(|_t, y| -y, |_t, _y| 0.3, 1.0, 4, seed, &mesh)
+                .unwrap();
+            let draws: Vec<f64> = {
+                let mut rng = seeded_rng(seed);
+                (0..5).map(|_| rng.r#gen::<f64>()).collect()
+            };
+            (path, ensemble.paths, draws)
+        };
+
+        let (first, second) = (run(2024), run(2024));
+        assert_eq!(first, second);
+        assert!(!first.1.contains(&first.0));           // Streams are derived, not the seed
+        assert_ne!(first, run(2025));
+    }
+
+    /// Tests whether, with the opt-in stability check, a step beyond the
+    /// forward Euler stability limit records an `unstable_step` warning that
+    /// appears in the JSON metadata, and that the check's evaluations are
+    /// counted in `stats`.
+    #[test]
+    fn test_warnings_in_json_metadata() {
+        let checked = |n: usize| {
+            let options = SolverOptions { stability_check: true, ..SolverOptions::default() };
+            let mesh = EulerSolver1D::generate_mesh(0.0, 1.0, n);
+            EulerSolver1D::with_options(|_t, y| -50.0 * y, mesh, 1.0, options).unwrap()
+        };
+        let stiff = checked(10);
+        assert_eq!(stiff.warnings.len(), 1);
+        assert_eq!(stiff.stats.evaluations, 10 + 2);    // Check stops at the first step
+        assert_eq!(stiff.warnings[0].code, "unstable_step");
+        assert_eq!(stiff.warnings[0].t, 0.0);
+
+        let path = std::env::temp_dir().join(format!("euler_warnings_{}.json", std::process::id()));
+        let path = path.to_str().unwrap();
+        stiff.export_to_json(path).unwrap();
+        let document: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap();
+        std::fs::remove_file(path).ok();
+        assert_eq!(document["warnings"][0]["code"], "unstable_step");
+        assert_eq!(document["warnings"][0]["t"], 0.0);
+        assert!(document["warnings"][0]["message"].as_str().unwrap().contains("stability"));
+
+        let stable = checked(100);
+        assert!(stable.warnings.is_empty());
+        assert_eq!(stable.stats.evaluations, 3 * 100);  // Step + finite-difference pair
+
+        let unchecked = EulerSolver1D::new(|_t, y| -50.0 * y, 0.0, 1.0, 1.0, 10).unwrap();
+        assert!(unchecked.warnings.is_empty());        // Off by default
+        assert_eq!(unchecked.stats.evaluations, 10);
+    }
+
+    /// Tests whether only the clamped component is held within its bounds
+    /// and that its clamp count matches the number of steps that hit it.
+    #[test]
+    fn test_nd_clamps_and_counts() {
+        // Component 0 decays at rate 2 and would overshoot below zero when h = 1
+        let solver = EulerSolverND::with_clamps(
+            |_t, y| vec![-2.0 * y[0] - 1.0, -y[1]],
+            0.0,
+            5.0,
+            vec![1.0, 1.0],
+            5,
+            vec![Some((0.0, f64::INFINITY)), None],
+        );
+        assert_eq!(solver.clamps.len(), 2);
+        assert!(solver.solution.iter().all(|y| y[0] >= 0.0));
+        assert_eq!(solver.clamp_counts, vec![5, 0]);    // y0 hits 0 each step
+        assert_eq!(solver.solution[1][1], 0.0);         // Unclamped: 1 + 1 * (-1)
+        let unclamped = EulerSolverND::new(|_t, y| vec![-y[0]], 0.0, 1.0, vec![1.0], 4);
+        assert_eq!(unclamped.clamp_counts, vec![0]);
+    }
+
+    /// Tests whether `resample_pow2` returns a power-of-two uniform grid whose
+    /// endpoints match the first and last solution points.
+    #[test]
+    fn test_resample_pow2() {
+        let mesh = vec![0.0, 0.3, 0.4, 1.0, 2.5];
+        let solver = EulerSolver1D::with_mesh(|t, y| t - y, mesh, 1.0).unwrap();
+        let (times, values) = solver.resample_pow2(100);
+        assert_eq!(times.len(), 128);
+        assert_eq!(values.len(), 128);
+        assert!(times.len().is_power_of_two());
+        assert_eq!((times[0], times[127]), (0.0, 2.5));
+        assert_eq!(values[0], solver.solution[0]);
+        assert_eq!(values[127], *solver.solution.last().unwrap());
+        assert_eq!(solver.resample_pow2(64).0.len(), 64);
+        assert_eq!(solver.resample_pow2(0).0.len(), 2);
+    }
+
+    /// Tests whether the cumulative max/min of an oscillating-then-decaying
+    /// solution are monotone envelopes, and that the export option adds them
+    /// as CSV columns and as fields of JSON and NDJSON points.
+    #[test]
+    fn test_cumulative_extremes_and_export() {
+        let solver = EulerSolver1D::new(
+            |t, y| -0.5 * y + 3.0 * (3.0 * t).cos() * (-t).exp(),
+            0.0,
+            10.0,
+            0.0,
+            1000,
+        )
+        .unwrap();
+        let (cummax, cummin) = (solver.cumulative_max(), solver.cumulative_min());
+        assert!(solver.solution.windows(2).any(|w| w[1] < w[0]));  // Really oscillates
+        assert!(cummax.windows(2).all(|w| w[1] >= w[0]));
+        assert!(cummin.windows(2).all(|w| w[1] <= w[0]));
+        for ((&y, &hi), &lo) in solver.solution.iter().zip(&cummax).zip(&cummin) {
+            assert!(lo <= y && y <= hi);
+        }
+        let peak = solver.solution.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+        assert_eq!(*cummax.last().unwrap(), peak);
+
+        let path =
+            std::env::temp_dir().join(format!("euler_cumulative_{}.csv", std::process::id()));
+        let output = OutputConfig {
+            csv_file: path.to_str().unwrap().to_string(),
+            format: OutputFormat::Csv,
+            precision: None,
+            delimiter: None,
+            include_cumulative: true,
+        };
+        solver.export(&output).unwrap();
+        let text = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+        let mut lines = text.lines();
+        assert_eq!(lines.next(), Some("t,y(t),cummax_y,cummin_y"));
+        let last: Vec<f64> = lines.last().unwrap().split(',').map(|v| v.parse().unwrap()).collect();
+        assert_eq!(last[2], peak);
+        assert_eq!(last[3], *cummin.last().unwrap());
+
+        let json = OutputConfig { format: OutputFormat::Json, ..output };
+        solver.export(&json).unwrap();
+        let document: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        let last = &document["solution"][1000];
+        assert_eq!(last["cummax_y"].as_f64(), Some(peak));
+        assert_eq!(last["cummin_y"].as_f64(), cummin.last().copied());
+
+        let ndjson = OutputConfig { format: OutputFormat::Ndjson, ..json };
+        solver.export(&ndjson).unwrap();
+        let text = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+        let last: serde_json::Value = serde_json::from_str(text.lines().last().unwrap()).unwrap();
+        assert_eq!(last["cummax_y"].as_f64(), Some(peak));
+
+        let mut plain = Vec::new();                 // Plain exports stay `{"t":..,"y":..}`
+        solver.export_ndjson(&mut plain).unwrap();
+        assert!(!String::from_utf8(plain).unwrap().contains("cummax_y"));
+    }
+
+    /// Tests whether the 10-90% rise time of the first-order step response
+    /// y' = 1 - y matches ln(9), and that falling or non-monotone solutions
+    /// and invalid fractions return `None`.
+    #[test]
+    fn test_rise_time() {
+        let step = EulerSolver1D::new(|_t, y| 1.0 - y, 0.0, 10.0, 0.0, 10_000).unwrap();
+        let rise = step.rise_time(0.1, 0.9).expect("Step response rises");
+        assert!((rise - 9.0f64.ln()).abs() < 1e-2, "rise = {}", rise);
+        assert_eq!(step.rise_time(0.9, 0.1), None);
+
+        let falling = EulerSolver1D::new(|_t, y| -y, 0.0, 5.0, 1.0, 100).unwrap();
+        assert_eq!(falling.rise_time(0.1, 0.9), None);
+        let wobbly = EulerSolver1D::new(|t, _y| 1.0 + 3.0 * (5.0 * t).cos(), 0.0, 5.0, 0.0, 500)
+            .unwrap();
+        assert_eq!(wobbly.rise_time(0.1, 0.9), None);
+    }
+
+    /// Tests whether the adaptive debug trace lists rejected attempts with
+    /// error estimates above the tolerance, and one accepted row per step of
+    /// the final mesh.
+    #[test]
+    fn test_adaptive_debug_trace_includes_rejections() {
+        let path = std::env::temp_dir().join(format!("euler_trace_{}.csv", std::process::id()));
+        let options = AdaptiveOptions {
+            tolerance: 1e-4,
+            initial_step: 1.0,                          // Far too large: forces rejections
+            debug_trace_path: Some(path.to_str().unwrap().to_string()),
+            ..AdaptiveOptions::default()
+        };
+        let calls = Rc::new(Cell::new(0));
+        let counter = Rc::clone(&calls);
+        let f = move |_t: f64, y: f64| {
+            counter.set(counter.get() + 1);
+            -y
+        };
+        let solver = EulerSolver1D::adaptive(f, 0.0, 2.0, 1.0, options).unwrap();
+        assert_eq!(*solver.mesh.last().unwrap(), 2.0);
+        assert_eq!(solver.stats.evaluations, calls.get());  // Search + final pass
+
+        let mut reader = csv::Reader::from_path(&path).unwrap();
+        let header = reader.headers().unwrap().clone();
+        assert_eq!(&header, vec!["attempt", "t", "h", "error_estimate", "accepted"]);
+        let rows: Vec<csv::StringRecord> = reader.records().map(|r| r.unwrap()).collect();
+        std::fs::remove_file(&path).ok();
+
+        let rejected: Vec<&csv::StringRecord> = rows.iter().filter(|r| &r[4] == "false").collect();
+        assert!(!rejected.is_empty());
+        for row in &rejected {
+            assert!(row[3].parse::<f64>().unwrap() > 1e-4);
+        }
+        let accepted: Vec<f64> =
+            rows.iter().filter(|r| &r[4] == "true").map(|r| r[1].parse().unwrap()).collect();
+        assert_eq!(accepted, solver.mesh[..solver.num_steps]);  // Each accepted step's start time
+    }
+
+    /// Tests whether the default trapezoidal check accepts a sampled exact
+    /// solution that the forward Euler residual rejects.
+    #[test]
+    fn test_verify_solution_csv_exact_solution() {
+        let path =
+            std::env::temp_dir().join(format!("euler_verify_exact_{}.csv", std::process::id()));
+        let path = path.to_str().unwrap();
+        let mut writer = csv::Writer::from_path(path).unwrap();
+        writer.write_record(["t", "y"]).unwrap();
+        for t in EulerSolver1D::generate_mesh(0.0, 1.0, 20) {
+            writer.write_record(&[t.to_string(), t.exp().to_string()]).unwrap();  // y' = y
+        }
+        writer.flush().unwrap();
+
+        let report = verify_solution_csv("y", path, 1e-3).unwrap();
+        assert_eq!(report.scheme, ResidualScheme::Trapezoidal);
+        assert!(report.passed(), "max residual {}", report.max_residual);
+        let euler = VerifyOptions { scheme: ResidualScheme::ForwardEuler, ..Default::default() };
+        assert!(!verify_solution_csv_with("y", path, 1e-3, &euler).unwrap().passed());
+        std::fs::remove_file(path).ok();
+    }
+
+    /// Tests whether a `;`-delimited export of `-k*y` verifies once the
+    /// delimiter and the `k` parameter are passed, and fails to parse without
+    /// them.
+    #[test]
+    fn test_verify_solution_csv_delimiter_and_parameters() {
+        let path =
+            std::env::temp_dir().join(format!("euler_verify_params_{}.csv", std::process::id()));
+        let parameters = HashMap::from([("k".to_string(), 2.0)]);
+        let f = parse_expression("-k*y".to_string(), &parameters).unwrap();
+        let solver = EulerSolver1D::new(f, 0.0, 1.0, 1.0, 10).unwrap();
+        let output = OutputConfig {
+            csv_file: path.to_str().unwrap().to_string(),
+            format: OutputFormat::Csv,
+            precision: None,
+            delimiter: Some(";".to_string()),
+            include_cumulative: false,
+        };
+        solver.export_in(&output, None).unwrap();
+
+        let path = path.to_str().unwrap();
+        let options = VerifyOptions {
+            scheme: ResidualScheme::ForwardEuler,
+            delimiter: b';',
+            parameters,
+        };
+        let report = verify_solution_csv_with("-k*y", path, 1e-9, &options).unwrap();
+        assert!(report.passed());
+        assert_eq!(report.num_points, 11);
+        assert!(matches!(
+            verify_solution_csv("-k*y", path, 1e-9),
+            Err(SolverError::InvalidExpression(_))
+        ));
+        std::fs::remove_file(path).ok();
+    }
+
+    /// Tests whether `mesh_tolerance` under `[solver]` reaches the solver
+    /// options when the solver is built from a config file.
+    #[test]
+    fn test_config_mesh_tolerance() {
+        let path = std::env::temp_dir().join(format!("euler_mesh_tol_{}.ini", std::process::id()));
+        std::fs::write(&path, "[mesh_1_d]\nn = 4\ndomain_start = 0.0\ndomain_end = 1.0\n\
+            [initial_conditions]\ny_0 = 1.0\n[ode_function]\nexpression = -y\n\
+            [output]\ncsv_file = out.csv\n[solver]\nmesh_tolerance = 1e-9\n").unwrap();
+        let config = SolverConfig::from_path(&path).expect("Failed to load config");
+        std::fs::remove_file(&path).ok();
+
+        let settings = config.solver.as_ref().expect("Expected a [solver] section");
+        assert_eq!(settings.mesh_tolerance, Some(1e-9));
+        assert_eq!(config.build_solver().unwrap().options.mesh_tolerance, 1e-9);
+    }
 }
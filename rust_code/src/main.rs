@@ -2,58 +2,115 @@
 //!
 //! This program solves a first-order ODE of the form dy/dt = f(t, y)
 //! using the forward Euler method. Solver parameters and the ODE function
-//! are provided through a config.ini file.
+//! are provided through a config file (`config.ini` by default, or the
+//! INI/TOML/JSON path given as the first command-line argument).
 //!
-//! The solution is printed to the console and saved to a CSV file.
+//! Relative output paths are resolved against `--workdir <dir>` when given,
+//! and against the current directory otherwise.
+//!
+//! The solution is printed to the console and saved to a CSV file. When the
+//! output file is `-`, stdout carries only the exported data and the console
+//! report goes to stderr instead.
 
 // --- Imports ---
 
-use config::Config as IniConfig;
-use rust_code::{SolverConfig, EulerSolver1D, parse_expression};
-use std::path::Path;
+use rust_code::{run_config, EulerSolver1D, SolverConfig};
+use std::env;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process;
+
+/// Command-line options of the solver binary
+struct CliArgs {
+    config_path: String,        // Config file, `config.ini` by default
+    workdir: Option<PathBuf>,   // Base directory for relative output paths
+}
+
+/// Parses `[--workdir <dir> | --workdir=<dir>] [config_path]`
+///
+/// # Returns
+/// * `Result<CliArgs, String>` - Parsed options or a usage message
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<CliArgs, String> {
+    let mut config_path = None;
+    let mut workdir = None;
+    while let Some(arg) = args.next() {
+        if arg == "--workdir" {
+            let dir = args.next().ok_or("`--workdir` expects a directory")?;
+            workdir = Some(PathBuf::from(dir));
+        } else if let Some(dir) = arg.strip_prefix("--workdir=") {
+            workdir = Some(PathBuf::from(dir));
+        } else if config_path.is_none() {
+            config_path = Some(arg);
+        } else {
+            return Err(format!("Unexpected argument `{}`", arg));
+        }
+    }
+    Ok(CliArgs {
+        config_path: config_path.unwrap_or_else(|| "config.ini".to_string()),
+        workdir,
+    })
+}
 
 /// Main entry point for the Euler solver.
 ///
-/// Loads configuration from `config.ini`, parses the ODE function,
-/// runs the solver, prints the result, and writes it to a CSV file.
+/// Runs the config-driven pipeline (load, solve, export) and prints the result.
 ///
 /// # Arguments
-/// None. Configuration is read from the `config.ini` file.
+/// Optional `--workdir <dir>` and path to the config file (defaults to `config.ini`).
 ///
 /// # Returns
 /// None. Results are printed and written to file.
 fn main() {
-    // Load and parse the configuration file
-    let settings = IniConfig::builder()
-        .add_source(config::File::from(Path::new("config.ini")))
-        .build()
-        .expect("Failed to read config");
-
-    // Deserialize the config file into typed struct
-    let config: SolverConfig = settings
-        .try_deserialize()
-        .expect("Failed to deserialize config");
-
-    // Parse the user-defined ODE expression into a callable function
-    let expression_fn = parse_expression(config.ode_function.expression)
-        .expect("Failed to parse expression");
-
-    // Create and run the Euler solver
-    let solver = EulerSolver1D::new(
-        expression_fn,
-        config.mesh_1_d.domain_start,
-        config.mesh_1_d.domain_end,
-        config.initial_conditions.y_0,
-        config.mesh_1_d.n,
-    );
+    let args = parse_args(env::args().skip(1)).unwrap_or_else(|e| {
+        eprintln!("{}\nUsage: rust_code [--workdir <dir>] [config_path]", e);
+        process::exit(2);
+    });
 
+    // Load the config, solve the ODE and export the result
+    let fail = |e: Box<dyn std::error::Error>| -> ! {
+        eprintln!("Failed to run solver: {}", e);
+        process::exit(1);
+    };
+    let config = SolverConfig::from_path(Path::new(&args.config_path)).unwrap_or_else(|e| fail(e));
+    let solver = run_config(&config, args.workdir.as_deref()).unwrap_or_else(|e| fail(e));
+
+    // Surface solver diagnostics (also exported in the JSON metadata)
+    for warning in &solver.warnings {
+        eprintln!("Warning {}", warning);
+    }
+
+    // Keep stdout parseable when the solution itself was streamed there
+    let result = if config.output.csv_file == "-" {
+        print_report(&solver, &mut io::stderr().lock())
+    } else {
+        print_report(&solver, &mut io::stdout().lock())
+    };
+    if let Err(e) = result {
+        eprintln!("Failed to print the solution: {}", e);
+        process::exit(1);
+    }
+}
+
+/// Prints the solution table, the performance summary and any event stop
+///
+/// # Arguments
+/// * `solver` - Finished solver
+/// * `out` - Destination of the report (stdout, or stderr when streaming)
+///
+/// # Returns
+/// * `io::Result<()>` - Ok or the write error
+fn print_report(solver: &EulerSolver1D, out: &mut dyn Write) -> io::Result<()> {
     // Print the results to the console
     for (t, y) in solver.mesh.iter().zip(solver.solution.iter()) {
-        println!("t = {:>5.2}, y = {:>8.5}", t, y);
+        writeln!(out, "t = {:>5.2}, y = {:>8.5}", t, y)?;
     }
 
-    // Write the results to a CSV file
-    if let Err(e) = solver.export_to_csv(&config.output.csv_file) {
-        eprintln!("Failed to export to CSV: {}", e);
+    // One-line performance summary
+    writeln!(out, "Solved {} steps in {}", solver.num_steps, solver.stats)?;
+
+    // Report an early stop caused by the event
+    if let Some(t_event) = solver.event_time {
+        writeln!(out, "Integration stopped early: event triggered at t = {:.5}", t_event)?;
     }
+    Ok(())
 }
@@ -0,0 +1,38 @@
+//! Binary-level test: streaming the solution to stdout (`csv_file = "-"`)
+//! must leave stdout parseable, with the console report on stderr.
+
+use std::process::Command;
+
+/// Runs the binary on an NDJSON-to-stdout config and checks that every
+/// stdout line is a `{"t", "y"}` record while the report lands on stderr.
+#[test]
+fn test_stdout_stream_is_not_mixed_with_report() {
+    let config = std::env::temp_dir().join(format!("euler_cli_{}.ini", std::process::id()));
+    std::fs::write(
+        &config,
+        "[mesh_1_d]\nn = 4\ndomain_start = 0.0\ndomain_end = 1.0\n\n\
+         [initial_conditions]\ny_0 = 1.0\n\n\
+         [ode_function]\nexpression = -y\n\n\
+         [output]\ncsv_file = \"-\"\nformat = ndjson\n",
+    )
+    .unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_rust_code"))
+        .arg(&config)
+        .output()
+        .expect("Failed to run the solver binary");
+    std::fs::remove_file(&config).ok();
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(lines.len(), 5);  // One record per mesh point, nothing else
+    for line in lines {
+        let record: serde_json::Value = serde_json::from_str(line).expect("Non-JSON line");
+        assert!(record["t"].is_number() && record["y"].is_number());
+    }
+
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("t =  1.00"));
+    assert!(stderr.contains("Solved 4 steps in"));
+}
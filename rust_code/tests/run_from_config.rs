@@ -0,0 +1,43 @@
+//! End-to-end test of the config-driven pipeline using `tests/fixtures/config.ini`.
+
+use rust_code::run_from_config_in;
+use std::path::Path;
+
+/// Runs the fixture config (dy/dt = -y on [0, 1] with 10 steps) and checks
+/// the solution against the exact Euler recurrence y[k] = 0.9^k, and that
+/// the CSV output was written. Runs in a temporary workdir so the relative
+/// `csv_file` does not depend on the current directory.
+#[test]
+fn test_run_from_config_fixture() {
+    let workdir = std::env::temp_dir().join(format!("euler_fixture_{}", std::process::id()));
+    std::fs::create_dir_all(workdir.join("target")).unwrap();
+
+    let solver = run_from_config_in(Path::new("tests/fixtures/config.ini"), Some(&workdir))
+        .expect("Failed to run fixture config");
+
+    assert_eq!(solver.solution.len(), 11);
+    for (k, &y) in solver.solution.iter().enumerate() {
+        assert!((y - 0.9f64.powi(k as i32)).abs() < 1e-12);
+    }
+    assert_eq!(solver.expression.as_deref(), Some("-k*y"));
+
+    let csv = std::fs::read_to_string(workdir.join("target/fixture_solution.csv")).unwrap();
+    assert_eq!(csv.lines().count(), 12);  // Header + 11 rows
+    std::fs::remove_dir_all(&workdir).unwrap();
+}
+
+/// Runs the fixture with an explicit working directory and checks that the
+/// relative `csv_file` lands under it, and that a missing workdir is rejected.
+#[test]
+fn test_run_from_config_in_workdir() {
+    let workdir = std::env::temp_dir().join(format!("euler_workdir_{}", std::process::id()));
+    std::fs::create_dir_all(workdir.join("target")).unwrap();
+
+    run_from_config_in(Path::new("tests/fixtures/config.ini"), Some(&workdir))
+        .expect("Failed to run fixture config in workdir");
+    let csv = std::fs::read_to_string(workdir.join("target/fixture_solution.csv")).unwrap();
+    assert_eq!(csv.lines().count(), 12);  // Header + 11 rows
+
+    std::fs::remove_dir_all(&workdir).unwrap();
+    assert!(run_from_config_in(Path::new("tests/fixtures/config.ini"), Some(&workdir)).is_err());
+}